@@ -0,0 +1,101 @@
+use alloc::vec::Vec;
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::Key;
+use cep47::contract_utils::{ContractContext, ContractStorage, Dict};
+
+const BLOCKLIST_DICT: &str = "blocklist";
+const COMPLIANCE_DICT: &str = "compliance_officers";
+
+pub trait Blocklist<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(&mut self) {
+        BlockedAccounts::init();
+        ComplianceOfficers::init();
+    }
+
+    fn is_blocked(&self, account: Key) -> bool {
+        BlockedAccounts::instance().is_blocked(&account)
+    }
+
+    fn block_accounts(&mut self, accounts: Vec<Key>) {
+        let blocklist = BlockedAccounts::instance();
+        for account in accounts {
+            blocklist.block(&account);
+        }
+    }
+
+    fn unblock_accounts(&mut self, accounts: Vec<Key>) {
+        let blocklist = BlockedAccounts::instance();
+        for account in accounts {
+            blocklist.unblock(&account);
+        }
+    }
+
+    fn is_compliance_officer(&self, account: Key) -> bool {
+        ComplianceOfficers::instance().is_officer(&account)
+    }
+
+    fn add_compliance_officer(&mut self, account: Key) {
+        ComplianceOfficers::instance().add_officer(&account);
+    }
+
+    fn revoke_compliance_officer(&mut self, account: Key) {
+        ComplianceOfficers::instance().revoke_officer(&account);
+    }
+}
+
+struct BlockedAccounts {
+    dict: Dict,
+}
+
+impl BlockedAccounts {
+    pub fn instance() -> BlockedAccounts {
+        BlockedAccounts {
+            dict: Dict::instance(BLOCKLIST_DICT),
+        }
+    }
+
+    pub fn init() {
+        storage::new_dictionary(BLOCKLIST_DICT).unwrap_or_revert();
+    }
+
+    pub fn is_blocked(&self, key: &Key) -> bool {
+        self.dict.get_by_key::<()>(key).is_some()
+    }
+
+    pub fn block(&self, key: &Key) {
+        self.dict.set_by_key(key, ());
+    }
+
+    pub fn unblock(&self, key: &Key) {
+        self.dict.remove_by_key::<()>(key);
+    }
+}
+
+struct ComplianceOfficers {
+    dict: Dict,
+}
+
+impl ComplianceOfficers {
+    pub fn instance() -> ComplianceOfficers {
+        ComplianceOfficers {
+            dict: Dict::instance(COMPLIANCE_DICT),
+        }
+    }
+
+    pub fn init() {
+        storage::new_dictionary(COMPLIANCE_DICT).unwrap_or_revert();
+    }
+
+    pub fn is_officer(&self, key: &Key) -> bool {
+        self.dict.get_by_key::<()>(key).is_some()
+    }
+
+    pub fn add_officer(&self, key: &Key) {
+        self.dict.set_by_key(key, ());
+    }
+
+    pub fn revoke_officer(&self, key: &Key) {
+        self.dict.remove_by_key::<()>(key);
+    }
+}
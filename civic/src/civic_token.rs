@@ -3,7 +3,12 @@
 #[macro_use]
 extern crate alloc;
 
-use alloc::{boxed::Box, collections::BTreeSet, string::String, vec::Vec};
+use alloc::{
+    boxed::Box,
+    collections::BTreeSet,
+    string::{String, ToString},
+    vec::Vec,
+};
 use cep47::{
     contract_utils::{AdminControl, ContractContext, OnChainContractStorage},
     Meta, TokenId, CEP47,
@@ -11,18 +16,106 @@ use cep47::{
 
 use casper_contract::{
     contract_api::{runtime, storage},
+    ext_ffi,
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
+    api_error,
+    bytesrepr::{self, FromBytes, ToBytes},
     runtime_args, ApiError, CLType, CLTyped, CLValue, ContractPackageHash, EntryPoint,
     EntryPointAccess, EntryPointType, EntryPoints, Group, Key, Parameter, RuntimeArgs, URef, U256,
 };
 
+mod blocklist;
+mod claim_registry;
+mod claim_store;
+mod compromised_gatekeepers;
+mod error;
 mod gatekeeper_control;
+mod gatekeeper_keys;
+mod gatekeeper_permissions;
+mod gatekeeper_suspensions;
+mod identity_registry;
+mod indexed_set;
+mod issuance_quotas;
+mod jurisdiction_policies;
+mod linked_keys;
+mod meta_keys;
+mod multisig;
+mod network_control;
+mod network_registry;
+mod relayers;
+mod role_index;
+mod role_timelock;
+mod subscriptions;
+mod sunset;
+mod token_actions;
+mod token_index;
+mod token_stamps;
+mod trusted_gateways;
+mod verification;
+mod verification_levels;
+use blocklist::Blocklist;
+use civic_claims::ClaimCommitment;
+use claim_registry::{ClaimCommitments, ClaimRegistry};
+use claim_store::{claim_meta_string, claims_meta_view, ClaimStore, ClaimValue, TypedClaims};
+use compromised_gatekeepers::CompromisedGatekeepers;
+use error::Error;
 use gatekeeper_control::GateKeeperControl;
+use gatekeeper_keys::GatekeeperKeys;
+use gatekeeper_permissions::{
+    GatekeeperPermissions, Permissions, EDIT_METADATA, FREEZE, MINT, REFRESH, REVOKE,
+};
+use gatekeeper_suspensions::GatekeeperSuspensions;
+use identity_registry::{Commitment, IdentityRegistry};
+use issuance_quotas::{Issuance, IssuanceQuotas, Quota};
+use jurisdiction_policies::{JurisdictionPolicies, Policy, PolicyId};
+use linked_keys::LinkedKeys;
+use multisig::{Multisig, Proposal, ProposalId};
+use network_control::{NetworkControl, NetworkId, DEFAULT_NETWORK};
+use network_registry::{FeePolicy, NetworkInfo, NetworkRegistry, TokenPolicy};
+use relayers::Relayers;
+use role_index::AdminIndex;
+use role_timelock::{RoleChange, RoleChangeId, RoleTimelock, SET_ROLE_CHANGE_DELAY};
+use subscriptions::{Notification, Subscriptions, MAX_NOTIFICATION_BATCH};
+use sunset::ContractSunset;
+use token_actions::{ActionReason, Dispute, TokenAction, TokenActions, NO_REASON};
+use token_index::{TokenIndex, MAX_PAGE_SIZE};
+use token_stamps::{Stamp, TokenStampRegistry, TokenStamps};
+use trusted_gateways::{NetworkMapping, TrustedGateways, MAX_FEDERATION_DEPTH};
+use verification::Reason;
+use verification_levels::{Level, VerificationLevels};
 
 pub const STATUS_KEY: &str = "status";
 pub const ACTIVE_STATUS: &str = "active";
+pub const FROZEN_STATUS: &str = "frozen";
+pub const REVOKED_STATUS: &str = "revoked";
+pub const EXPIRY_KEY: &str = "expiry";
+
+/// Status, expiry and network of a token, as listed by the paged token queries.
+type TokenSummary = (String, Option<u64>, NetworkId);
+
+struct MintOptions {
+    network: NetworkId,
+    level: Level,
+    identity_commitment: Commitment,
+    claim_commitments: ClaimCommitments,
+    jurisdiction: Option<String>,
+    typed_claims: TypedClaims,
+}
+
+impl MintOptions {
+    fn from_named_args() -> MintOptions {
+        MintOptions {
+            network: optional_named_arg("network"),
+            level: optional_named_arg("level"),
+            identity_commitment: runtime::get_named_arg("identity_commitment"),
+            claim_commitments: optional_named_arg("claim_commitments"),
+            jurisdiction: optional_named_arg("jurisdiction"),
+            typed_claims: optional_named_arg("typed_claims"),
+        }
+    }
+}
 
 #[derive(Default)]
 struct GatewayToken(OnChainContractStorage);
@@ -36,38 +129,876 @@ impl ContractContext<OnChainContractStorage> for GatewayToken {
 impl CEP47<OnChainContractStorage> for GatewayToken {}
 impl AdminControl<OnChainContractStorage> for GatewayToken {}
 impl GateKeeperControl<OnChainContractStorage> for GatewayToken {}
+impl NetworkControl<OnChainContractStorage> for GatewayToken {}
+impl IdentityRegistry<OnChainContractStorage> for GatewayToken {}
+impl ClaimRegistry<OnChainContractStorage> for GatewayToken {}
+impl VerificationLevels<OnChainContractStorage> for GatewayToken {}
+impl Blocklist<OnChainContractStorage> for GatewayToken {}
+impl JurisdictionPolicies<OnChainContractStorage> for GatewayToken {}
+impl IssuanceQuotas<OnChainContractStorage> for GatewayToken {}
+impl Multisig<OnChainContractStorage> for GatewayToken {}
+impl RoleTimelock<OnChainContractStorage> for GatewayToken {}
+impl AdminIndex<OnChainContractStorage> for GatewayToken {}
+impl TokenIndex<OnChainContractStorage> for GatewayToken {}
+impl NetworkRegistry<OnChainContractStorage> for GatewayToken {}
+impl GatekeeperKeys<OnChainContractStorage> for GatewayToken {}
+impl CompromisedGatekeepers<OnChainContractStorage> for GatewayToken {}
+impl GatekeeperSuspensions<OnChainContractStorage> for GatewayToken {}
+impl GatekeeperPermissions<OnChainContractStorage> for GatewayToken {}
+impl ClaimStore<OnChainContractStorage> for GatewayToken {}
+impl TokenStampRegistry<OnChainContractStorage> for GatewayToken {}
+impl TokenActions<OnChainContractStorage> for GatewayToken {}
+impl LinkedKeys<OnChainContractStorage> for GatewayToken {}
+impl Relayers<OnChainContractStorage> for GatewayToken {}
+impl Subscriptions<OnChainContractStorage> for GatewayToken {}
+impl TrustedGateways<OnChainContractStorage> for GatewayToken {}
+impl ContractSunset<OnChainContractStorage> for GatewayToken {}
 impl GatewayToken {
     fn constructor(&mut self, name: String, symbol: String, meta: Meta) {
         CEP47::init(self, name, symbol, meta);
         AdminControl::init(self);
         GateKeeperControl::init(self);
+        NetworkControl::init(self);
+        IdentityRegistry::init(self);
+        ClaimRegistry::init(self);
+        VerificationLevels::init(self);
+        Blocklist::init(self);
+        JurisdictionPolicies::init(self);
+        IssuanceQuotas::init(self);
+        Multisig::init(self);
+        RoleTimelock::init(self);
+        AdminIndex::init(self);
+        TokenIndex::init(self);
+        NetworkRegistry::init(self);
+        GatekeeperKeys::init(self);
+        CompromisedGatekeepers::init(self);
+        GatekeeperSuspensions::init(self);
+        GatekeeperPermissions::init(self);
+        ClaimStore::init(self);
+        TokenStampRegistry::init(self);
+        TokenActions::init(self);
+        LinkedKeys::init(self);
+        Relayers::init(self);
+        Subscriptions::init(self);
+        TrustedGateways::init(self);
+    }
+
+    fn mint_token(
+        &mut self,
+        recipient: Key,
+        token_id: Option<TokenId>,
+        mut token_meta: Meta,
+        options: MintOptions,
+    ) {
+        let network = options.network;
+        self.assert_network_registered(network);
+        let (default_lifetime, _, _) = self.network_token_policy(network);
+        if default_lifetime > 0 && !token_meta.contains_key(EXPIRY_KEY) {
+            let expiry = block_time().saturating_add(default_lifetime);
+            token_meta.insert(EXPIRY_KEY.into(), expiry.to_string());
+        }
+        self.assert_level_allowed(network, self.caller_identity(), options.level);
+        let token_id = self
+            .mint(recipient, token_id.map(|x| vec![x]), vec![token_meta])
+            .unwrap_or_revert()
+            .pop()
+            .unwrap_or_revert();
+        self.record_issuance(&token_id, self.caller_identity(), block_time());
+        self.stamp_issuance(&token_id, (block_time(), self.gatekeeper_caller()));
+        self.stamp_refresh(&token_id, block_time());
+        self.index_token(token_id.clone());
+        self.set_token_network(&token_id, network);
+        self.set_token_level(&token_id, options.level);
+        let allow_multiple = self.allows_multiple_accounts(network);
+        self.register_identity(
+            network,
+            &token_id,
+            options.identity_commitment,
+            recipient,
+            allow_multiple,
+        );
+        self.set_claim_commitments(&token_id, options.claim_commitments);
+        self.set_token_jurisdiction(&token_id, options.jurisdiction);
+        self.set_typed_claims(&token_id, options.typed_claims);
+    }
+
+    /// Sets or removes a typed claim together with its entry in the `token_meta` view.
+    fn update_typed_claim(&mut self, token_id: TokenId, claim: String, value: Option<CLValue>) {
+        self.assert_claim_name_allowed(&claim);
+        let mut token_meta = self.token_meta(token_id.clone()).unwrap_or_revert();
+        match &value {
+            Some(value) => token_meta.insert(claim.clone(), claim_meta_string(value)),
+            None => token_meta.remove(&claim),
+        };
+        self.assert_caller_edits_meta(&token_id, &token_meta);
+        self.set_token_meta(token_id.clone(), token_meta)
+            .unwrap_or_revert();
+        self.set_typed_claim(&token_id, claim, value);
+        self.touch_token(&token_id);
+    }
+
+    fn upgrade_token_level(&mut self, token_id: TokenId, level: Level) {
+        self.owner_of(token_id.clone()).unwrap_or_revert();
+        let network = self.token_network(&token_id);
+        self.assert_level_allowed(network, self.caller_identity(), level);
+        self.set_token_level(&token_id, level);
+        self.touch_token(&token_id);
+    }
+
+    /// Restarts the refresh window of a token and extends its expiry by the
+    /// network's default lifetime.
+    fn refresh_token(&mut self, token_id: TokenId) {
+        let mut token_meta = self.token_meta(token_id.clone()).unwrap_or_revert();
+        let network = self.token_network(&token_id);
+        let (default_lifetime, refreshable, _) = self.network_token_policy(network);
+        if !refreshable {
+            runtime::revert(Error::TokenNotRefreshable);
+        }
+        let now = block_time();
+        if default_lifetime > 0 {
+            let expiry = now.saturating_add(default_lifetime);
+            token_meta.insert(EXPIRY_KEY.into(), expiry.to_string());
+            self.set_token_meta(token_id.clone(), token_meta)
+                .unwrap_or_revert();
+        }
+        self.stamp_refresh(&token_id, now);
+        self.touch_token(&token_id);
+    }
+
+    /// Whether the token's network refresh interval has passed since its last refresh.
+    fn needs_refresh(&self, token_id: &TokenId) -> bool {
+        let interval = self.network_refresh_interval(self.token_network(token_id));
+        let last_refreshed = self.last_refreshed(token_id).unwrap_or_default();
+        interval > 0 && block_time() >= last_refreshed.saturating_add(interval)
+    }
+
+    fn link_key(&mut self, key: Key) {
+        let holder = self.get_caller();
+        if !self.is_kyc_proved(holder, None) {
+            runtime::revert(Error::PermissionDenied);
+        }
+        self.propose_link(holder, key);
+    }
+
+    fn caller_stamp(&self) -> Stamp {
+        (block_time(), self.gatekeeper_caller())
+    }
+
+    /// Records the caller and block time as the latest update of a token.
+    fn touch_token(&mut self, token_id: &TokenId) {
+        self.stamp_update(token_id, self.caller_stamp());
+    }
+
+    fn set_token_status(&mut self, token_id: &TokenId, status: String) {
+        let mut token_meta = self.token_meta(token_id.clone()).unwrap_or_revert();
+        token_meta.insert(STATUS_KEY.into(), status);
+        self.set_token_meta(token_id.clone(), token_meta)
+            .unwrap_or_revert();
+        self.touch_token(token_id);
+    }
+
+    /// Freezes or revokes a token, keeping the status it had before it was first
+    /// held so that a dispute can reinstate it.
+    fn hold_token(&mut self, token_id: TokenId, status: &str, action: &str, reason: ActionReason) {
+        if self.held_status(&token_id).is_none() {
+            let token_meta = self.token_meta(token_id.clone()).unwrap_or_revert();
+            let previous_status = token_meta.get(STATUS_KEY).cloned().unwrap_or_default();
+            self.set_held_status(&token_id, Some(previous_status));
+        }
+        self.set_token_status(&token_id, status.into());
+        self.record_action(&token_id, (action.into(), reason, self.caller_stamp()));
+        let owner = self.owner_of(token_id.clone()).unwrap_or_revert();
+        self.push_notification((owner, token_id, (action.into(), reason)));
+    }
+
+    /// Freezes a token unless it is revoked, so that `unfreeze` cannot undo a
+    /// revocation.
+    fn freeze_token(&mut self, token_id: TokenId, reason: ActionReason) {
+        let token_meta = self.token_meta(token_id.clone()).unwrap_or_revert();
+        if token_meta.get(STATUS_KEY).map(String::as_str) == Some(REVOKED_STATUS) {
+            runtime::revert(Error::TokenRevoked);
+        }
+        self.hold_token(token_id, FROZEN_STATUS, "freeze", reason);
+    }
+
+    /// Restores the status a frozen token had before it was first held.
+    fn unfreeze_token(&mut self, token_id: TokenId) {
+        let token_meta = self.token_meta(token_id.clone()).unwrap_or_revert();
+        if token_meta.get(STATUS_KEY).map(String::as_str) != Some(FROZEN_STATUS) {
+            runtime::revert(Error::TokenNotHeld);
+        }
+        if self.open_dispute(&token_id).is_some() {
+            runtime::revert(Error::DisputeOpen);
+        }
+        let status = self.held_status(&token_id).unwrap_or_revert();
+        self.set_held_status(&token_id, None);
+        self.set_token_status(&token_id, status);
+        self.record_action(
+            &token_id,
+            ("unfreeze".into(), NO_REASON, self.caller_stamp()),
+        );
+    }
+
+    /// Runs the callback of a subscriber for up to `limit` of its pending
+    /// notifications. A reverting callback only reverts this delivery, leaving the
+    /// cursor in place.
+    fn deliver_notifications(&mut self, subscriber: Key, limit: u64) {
+        let entry_point = self
+            .subscriber_callback(subscriber)
+            .unwrap_or_revert_with(Error::UnknownSubscriber);
+        let package = subscriber.into_hash().unwrap_or_revert();
+        let cursor = self.subscriber_cursor(subscriber).unwrap_or_revert();
+        let end = self
+            .notification_count()
+            .min(cursor.saturating_add(limit.min(MAX_NOTIFICATION_BATCH)));
+        for index in cursor..end {
+            let (account, token_id, (action, reason)): Notification =
+                self.notification(index).unwrap_or_revert();
+            runtime::call_versioned_contract::<()>(
+                ContractPackageHash::new(package),
+                None,
+                &entry_point,
+                runtime_args! {
+                    "account" => account,
+                    "token_id" => token_id,
+                    "action" => action,
+                    "reason" => reason,
+                },
+            );
+        }
+        self.set_subscriber_cursor(subscriber, end);
+    }
+
+    /// Moves the caller's cursor past notifications its callback cannot handle.
+    fn skip_notifications(&mut self, count: u64) {
+        let subscriber = self.get_caller();
+        let cursor = self
+            .subscriber_cursor(subscriber)
+            .unwrap_or_revert_with(Error::UnknownSubscriber);
+        let end = self.notification_count().min(cursor.saturating_add(count));
+        self.set_subscriber_cursor(subscriber, end);
+    }
+
+    fn dispute_token(&mut self, token_id: TokenId, evidence_hash: [u8; 32]) {
+        let owner = self.owner_of(token_id.clone()).unwrap_or_revert();
+        if owner != self.get_caller() {
+            runtime::revert(Error::PermissionDenied);
+        }
+        if self.held_status(&token_id).is_none() {
+            runtime::revert(Error::TokenNotHeld);
+        }
+        if self.open_dispute(&token_id).is_some() {
+            runtime::revert(Error::DisputeOpen);
+        }
+        let stamp = self.caller_stamp();
+        self.set_open_dispute(&token_id, Some((evidence_hash, stamp)));
+        self.record_action(&token_id, ("dispute".into(), NO_REASON, stamp));
+    }
+
+    /// Closes the open dispute of a token, either restoring its status from before
+    /// the hold or confirming the hold.
+    fn resolve_dispute(&mut self, token_id: TokenId, reinstate: bool) {
+        self.open_dispute(&token_id)
+            .unwrap_or_revert_with(Error::UnknownDispute);
+        self.set_open_dispute(&token_id, None);
+        let action = if reinstate {
+            let status = self.held_status(&token_id).unwrap_or_revert();
+            self.set_held_status(&token_id, None);
+            self.set_token_status(&token_id, status);
+            "reinstate"
+        } else {
+            "confirm"
+        };
+        self.record_action(&token_id, (action.into(), NO_REASON, self.caller_stamp()));
+    }
+
+    fn burn_token(&mut self, owner: Key, token_id: TokenId) {
+        let network = self.token_network(&token_id);
+        self.burn_internal(owner, vec![token_id.clone()])
+            .unwrap_or_revert();
+        self.release_identity(network, &token_id, owner);
+        self.remove_claim_commitments(&token_id);
+        self.remove_token_level(&token_id);
+        self.set_token_jurisdiction(&token_id, None);
+        self.remove_typed_claims(&token_id);
+        self.remove_token_stamps(&token_id);
+        self.close_token_actions(&token_id, self.caller_stamp());
+        self.push_notification((owner, token_id.clone(), ("burn".into(), NO_REASON)));
+        self.release_issuance(&token_id);
+        self.unindex_token(&token_id);
+        self.remove_token_network(&token_id);
+    }
+
+    fn transfer_tokens(&mut self, owner: Key, recipient: Key, token_ids: Vec<TokenId>) {
+        self.transfer_from_internal(owner, recipient, token_ids.clone())
+            .unwrap_or_revert();
+        for token_id in token_ids {
+            let network = self.token_network(&token_id);
+            self.move_identity(network, &token_id, owner, recipient);
+            self.touch_token(&token_id);
+        }
+    }
+
+    /// Account whose tokens verify the provided key, the holder a key is linked to.
+    fn holder_of(&self, account: Key) -> Key {
+        self.linked_holder(account).unwrap_or(account)
+    }
+
+    fn verify(&self, account: Key, index: Option<U256>) -> Reason {
+        let holder = self.holder_of(account);
+        if self.is_blocked(account) || self.is_blocked(holder) {
+            return verification::BLOCKED;
+        }
+        match self.get_token_by_index(holder, index.unwrap_or_default()) {
+            Some(token_id) => self.verify_token(&token_id),
+            None => verification::NO_TOKEN,
+        }
+    }
+
+    /// Like `verify`, but rejects a valid token that is due for a refresh.
+    fn verify_fresh(&self, account: Key, index: Option<U256>) -> Reason {
+        let reason = self.verify(account, index);
+        if reason != verification::VERIFIED {
+            return reason;
+        }
+        let token_id = self
+            .get_token_by_index(self.holder_of(account), index.unwrap_or_default())
+            .unwrap_or_revert();
+        if self.needs_refresh(&token_id) {
+            return verification::NEEDS_REFRESH;
+        }
+        verification::VERIFIED
+    }
+
+    fn verify_token(&self, token_id: &TokenId) -> Reason {
+        let token_metadata = match self.token_meta(token_id.clone()) {
+            Some(token_metadata) => token_metadata,
+            None => return verification::NO_TOKEN,
+        };
+        if token_metadata.get(STATUS_KEY).map(String::as_str) != Some(ACTIVE_STATUS) {
+            return verification::INACTIVE;
+        }
+        if let Some(expiry) = token_metadata.get(EXPIRY_KEY) {
+            // An expiry that does not parse as a block time in milliseconds fails closed.
+            let expired = expiry
+                .parse::<u64>()
+                .map_or(true, |expiry| expiry <= block_time());
+            if expired {
+                return verification::EXPIRED;
+            }
+        }
+        if let Some((issuer, issued_at)) = self.token_issuance(token_id) {
+            if self.is_compromised_issuance(issuer, issued_at) {
+                return verification::ISSUER_COMPROMISED;
+            }
+        }
+        verification::VERIFIED
+    }
+
+    /// Checks every token of the account against the policy and returns the reason
+    /// of the last token checked when none of them is eligible.
+    fn is_eligible(&self, account: Key, policy_id: PolicyId) -> Reason {
+        self.policy(policy_id)
+            .unwrap_or_revert_with(Error::UnknownPolicy);
+        let holder = self.holder_of(account);
+        if self.is_blocked(account) || self.is_blocked(holder) {
+            return verification::BLOCKED;
+        }
+        let mut reason = verification::NO_TOKEN;
+        let balance = self.balance_of(holder);
+        let mut index = U256::zero();
+        while index < balance {
+            let token_id = self.get_token_by_index(holder, index).unwrap_or_revert();
+            reason = self.verify_token(&token_id);
+            if reason == verification::VERIFIED {
+                if self.is_jurisdiction_allowed(policy_id, &token_id) {
+                    return verification::VERIFIED;
+                }
+                reason = verification::JURISDICTION_NOT_ALLOWED;
+            }
+            index += U256::one();
+        }
+        reason
+    }
+
+    fn token_summary(&self, token_id: TokenId) -> (TokenId, TokenSummary) {
+        let token_meta = self.token_meta(token_id.clone()).unwrap_or_default();
+        let status = token_meta.get(STATUS_KEY).cloned().unwrap_or_default();
+        let expiry = token_meta
+            .get(EXPIRY_KEY)
+            .and_then(|expiry| expiry.parse().ok());
+        let network = self.token_network(&token_id);
+        (token_id, (status, expiry, network))
+    }
+
+    fn tokens_of(&self, owner: Key, offset: u64, limit: u64) -> Vec<(TokenId, TokenSummary)> {
+        let balance = self.balance_of(owner);
+        page(offset, limit)
+            .take_while(|index| U256::from(*index) < balance)
+            .filter_map(|index| self.get_token_by_index(owner, U256::from(index)))
+            .map(|token_id| self.token_summary(token_id))
+            .collect()
+    }
+
+    fn tokens(&self, offset: u64, limit: u64) -> Vec<(TokenId, TokenSummary)> {
+        page(offset, limit)
+            .map(|index| self.token_at(index))
+            .take_while(Option::is_some)
+            .flatten()
+            .map(|token_id| self.token_summary(token_id))
+            .collect()
+    }
+
+    /// Scans a page of the global token index for tokens of a compromised gatekeeper.
+    fn compromised_tokens(&self, gatekeeper: Key, offset: u64, limit: u64) -> Vec<TokenId> {
+        page(offset, limit)
+            .map(|index| self.token_at(index))
+            .take_while(Option::is_some)
+            .flatten()
+            .filter(|token_id| match self.token_issuance(token_id) {
+                Some((issuer, issued_at)) => {
+                    issuer == gatekeeper && self.is_compromised_issuance(issuer, issued_at)
+                }
+                None => false,
+            })
+            .collect()
     }
 
     fn is_kyc_proved(&self, account: Key, index: Option<U256>) -> bool {
-        let token_id = self.get_token_by_index(account, index.unwrap_or_default());
-        if token_id.is_none() {
-            return false;
+        self.verify(account, index) == verification::VERIFIED
+    }
+
+    /// Local verification of the holder's first token in a network.
+    fn verify_in_network(&self, account: Key, network: NetworkId) -> Reason {
+        let holder = self.holder_of(account);
+        if self.is_blocked(account) || self.is_blocked(holder) {
+            return verification::BLOCKED;
+        }
+        let balance = self.balance_of(holder).as_u64();
+        (0..balance)
+            .filter_map(|index| self.get_token_by_index(holder, U256::from(index)))
+            .find(|token_id| self.token_network(token_id) == network)
+            .map_or(verification::NO_TOKEN, |token_id| {
+                self.verify_token(&token_id)
+            })
+    }
+
+    /// Verifies an account locally, in a network or across all of them, and asks
+    /// the trusted gateways when it holds no token here. `depth` counts the gateway
+    /// hops already taken, so gateways trusting each other cannot call in a loop.
+    /// Gateways are asked in index order and a gateway call that reverts, e.g. on a
+    /// disabled package, reverts the whole lookup until the gateway is distrusted.
+    fn federated_verify(&self, account: Key, network: Option<NetworkId>, depth: u8) -> Reason {
+        let reason = match network {
+            Some(network) => self.verify_in_network(account, network),
+            None => self.verify(account, None),
+        };
+        if reason != verification::NO_TOKEN || depth >= MAX_FEDERATION_DEPTH {
+            return reason;
+        }
+        for index in 0..self.trusted_gateway_count() {
+            let gateway = self.trusted_gateway_at(index).unwrap_or_revert();
+            let remote_network = match network {
+                Some(network) => match self.gateway_networks(gateway).get(&network) {
+                    Some(remote_network) => Some(*remote_network),
+                    None => continue,
+                },
+                None => None,
+            };
+            let gateway_reason: Reason = runtime::call_versioned_contract(
+                ContractPackageHash::new(gateway.into_hash().unwrap_or_revert()),
+                None,
+                "federated_verify",
+                runtime_args! {
+                    "account" => account,
+                    "network" => remote_network,
+                    "depth" => depth + 1,
+                },
+            );
+            if gateway_reason == verification::VERIFIED {
+                return verification::VERIFIED;
+            }
         }
-        let token_metadata = self.token_meta(token_id.unwrap());
-        if token_metadata.is_none() {
+        reason
+    }
+
+    /// Verification behind the `verify` and `is_kyc_proved` entry points. Only a
+    /// lookup without a token index falls back to the trusted gateways.
+    fn verify_any_gateway(&self, account: Key, index: Option<U256>) -> Reason {
+        match index {
+            Some(_) => self.verify(account, index),
+            None => self.federated_verify(account, None, 0),
+        }
+    }
+
+    fn trust_external_gateway(&mut self, gateway: Key, networks: NetworkMapping) {
+        gateway
+            .into_hash()
+            .unwrap_or_revert_with(ApiError::UnexpectedKeyVariant);
+        self.trust_gateway(gateway, networks);
+    }
+
+    fn is_kyc_proved_at_level(&self, account: Key, level: Level, index: Option<U256>) -> bool {
+        if !self.is_kyc_proved(account, index) {
             return false;
         }
-        if let Some(status) = token_metadata.unwrap().get(STATUS_KEY) {
-            if status.eq(ACTIVE_STATUS) {
-                return true;
+        let token_id = self
+            .get_token_by_index(self.holder_of(account), index.unwrap_or_default())
+            .unwrap_or_revert();
+        self.token_level(&token_id) >= level
+    }
+
+    fn propose_action(&mut self, entry_point: String, args: Vec<u8>) -> ProposalId {
+        let proposer = self.get_caller();
+        let proposal_id = self.propose(entry_point, args, proposer, block_time());
+        self.execute_if_approved(proposal_id);
+        proposal_id
+    }
+
+    fn approve_proposal(&mut self, proposal_id: ProposalId) {
+        let approver = self.get_caller();
+        self.approve(proposal_id, approver, block_time());
+        self.execute_if_approved(proposal_id);
+    }
+
+    /// Runs a proposal once enough current admins approved it. Approvals of
+    /// accounts that lost the admin role in the meantime do not count.
+    fn execute_if_approved(&mut self, proposal_id: ProposalId) {
+        let approvals = self
+            .proposal_approvals(proposal_id)
+            .into_iter()
+            .filter(|approver| self.is_admin(*approver))
+            .count();
+        if approvals < self.approval_threshold() as usize {
+            return;
+        }
+        let (entry_point, args, _) = self.take_proposal(proposal_id);
+        let args: RuntimeArgs = bytesrepr::deserialize(args).unwrap_or_revert();
+        match entry_point.as_str() {
+            "grant_admin" | "revoke_admin" => {
+                self.change_role(&entry_point, named_arg(&args, "admin"))
+            }
+            "emergency_revoke_admin" => self.revoke_admin_role(named_arg(&args, "admin")),
+            "grant_gatekeeper" => self.change_role(&entry_point, named_arg(&args, "gatekeeper")),
+            "transfer_from" => self.transfer_tokens(
+                named_arg(&args, "sender"),
+                named_arg(&args, "recipient"),
+                named_arg(&args, "token_ids"),
+            ),
+            "set_multisig_config" => self.configure_multisig(
+                named_arg(&args, "threshold"),
+                named_arg(&args, "proposal_lifetime"),
+            ),
+            "set_role_change_delay" => self.change_role_change_delay(named_arg(&args, "delay")),
+            "sunset" => self.sunset_contract(
+                named_arg(&args, "successor"),
+                named_arg(&args, "forward_reads"),
+            ),
+            "trust_gateway" => self
+                .trust_external_gateway(named_arg(&args, "gateway"), named_arg(&args, "networks")),
+            _ => runtime::revert(Error::UnknownAction),
+        }
+    }
+
+    /// Sets the multisig config once the threshold is reachable by the current admins.
+    fn configure_multisig(&mut self, threshold: u32, proposal_lifetime: u64) {
+        if threshold == 0 || u64::from(threshold) > self.admin_count() {
+            runtime::revert(Error::InvalidThreshold);
+        }
+        self.set_multisig_config(threshold, proposal_lifetime);
+    }
+
+    /// Applies a role change right away, or schedules it while a role change delay is set.
+    fn change_role(&mut self, change: &str, account: Key) {
+        if self.role_change_delay() == 0 {
+            self.apply_role_change(change, account);
+        } else {
+            self.schedule_role_change(change.into(), account, self.get_caller(), block_time());
+        }
+    }
+
+    /// Changes the role change delay under the current one, so it cannot be lifted instantly.
+    fn change_role_change_delay(&mut self, delay: u64) {
+        if self.role_change_delay() == 0 {
+            self.set_role_change_delay(delay);
+        } else {
+            self.schedule_role_change_delay(delay, self.get_caller(), block_time());
+        }
+    }
+
+    fn execute_role_change(&mut self, change_id: RoleChangeId) {
+        let delay = self.scheduled_role_change_delay(change_id);
+        let (change, account, _) = self.take_role_change(change_id, block_time());
+        if change == SET_ROLE_CHANGE_DELAY {
+            self.set_role_change_delay(delay.unwrap_or_default());
+        } else {
+            self.apply_role_change(&change, account);
+        }
+    }
+
+    fn apply_role_change(&mut self, change: &str, account: Key) {
+        match change {
+            "grant_admin" => self.grant_admin_role(account),
+            "revoke_admin" => self.revoke_admin_role(account),
+            "grant_gatekeeper" => {
+                self.add_gatekeeper(account);
+                self.record_identity(account);
+                self.reinstate(self.key_identity(account));
             }
+            "revoke_gatekeeper" => self.revoke_gatekeeper(account),
+            _ => runtime::revert(Error::UnknownAction),
+        }
+    }
+
+    fn grant_admin_role(&mut self, admin: Key) {
+        self.add_admin_without_checked(admin);
+        self.index_admin(admin);
+    }
+
+    /// Revokes an admin as long as enough admins remain to reach the approval threshold.
+    fn revoke_admin_role(&mut self, admin: Key) {
+        self.disable_admin(admin);
+        self.unindex_admin(admin);
+        if u64::from(self.approval_threshold()) > self.admin_count() {
+            runtime::revert(Error::InvalidThreshold);
+        }
+    }
+
+    /// Key authorized by the gatekeeper entry points: the signing account when the
+    /// immediate caller is a relayer contract registered by its identity, the
+    /// immediate caller otherwise. Admin and key management entry points keep
+    /// authorizing the immediate caller.
+    fn gatekeeper_caller(&self) -> Key {
+        let caller = self.get_caller();
+        let origin = self.origin_caller();
+        match self.gatekeeper_identity(origin) {
+            Some(identity) if caller != origin && self.is_relayer(caller, identity) => origin,
+            _ => caller,
+        }
+    }
+
+    /// Identity under which the caller's issuance is recorded and limited.
+    fn caller_identity(&self) -> Key {
+        let caller = self.gatekeeper_caller();
+        self.gatekeeper_identity(caller).unwrap_or(caller)
+    }
+
+    /// Whether the caller is a global gatekeeper or a gatekeeper of the network,
+    /// and is not suspended.
+    fn caller_issues_in(&self, network: NetworkId) -> bool {
+        if self.is_suspended(self.caller_identity(), block_time()) {
+            return false;
+        }
+        let caller = self.gatekeeper_caller();
+        if self.is_gatekeeper_key(caller) {
+            return true;
+        }
+        match self.gatekeeper_identity(caller) {
+            Some(identity) => self.is_network_gatekeeper(network, identity),
+            None => false,
+        }
+    }
+
+    fn rotate_gatekeeper_key(&mut self, new_key: Key) {
+        let caller = self.get_caller();
+        self.gatekeeper_identity(caller)
+            .unwrap_or_revert_with(Error::PermissionDenied);
+        self.propose_key_rotation(caller, new_key);
+    }
+
+    /// Completes the rotation `old_key` proposed to the calling key. The global
+    /// gatekeeper role moves with the key, everything recorded under the identity
+    /// stays in place.
+    fn confirm_gatekeeper_key(&mut self, old_key: Key) {
+        let new_key = self.get_caller();
+        self.confirm_key_rotation(old_key, new_key);
+        if self.is_gatekeeper_key(old_key) {
+            self.revoke_gatekeeper(old_key);
+            self.add_gatekeeper(new_key);
+        }
+    }
+
+    /// Registers or removes a relayer contract for the caller's gatekeeper identity.
+    fn set_gatekeeper_relayer(&mut self, relayer: Key, allowed: bool) {
+        let identity = self
+            .gatekeeper_identity(self.get_caller())
+            .unwrap_or_revert_with(Error::PermissionDenied);
+        self.set_relayer(relayer, identity, allowed);
+    }
+
+    fn resign_gatekeeper(&mut self) {
+        let caller = self.get_caller();
+        let identity = self
+            .gatekeeper_identity(caller)
+            .unwrap_or_revert_with(Error::PermissionDenied);
+        self.revoke_gatekeeper(caller);
+        self.resign(identity);
+    }
+
+    fn assert_caller_issues_in(&self, network: NetworkId, permissions: Permissions) {
+        if !self.caller_issues_in(network)
+            || !self.has_permissions(self.caller_identity(), permissions)
+        {
+            runtime::revert(Error::PermissionDenied);
+        }
+    }
+
+    /// Admins manage every token, gatekeepers those of their networks within their permissions.
+    fn assert_caller_manages_token(&self, token_id: &TokenId, permissions: Permissions) {
+        if self.is_admin(self.get_caller()) {
+            return;
+        }
+        self.assert_caller_issues_in(self.token_network(token_id), permissions);
+    }
+
+    /// Checks that the caller may replace the metadata of a token with `token_meta`.
+    /// Nobody may change the status this way; gatekeepers may only change `expiry`
+    /// and keys in their own namespace.
+    fn assert_caller_edits_meta(&self, token_id: &TokenId, token_meta: &Meta) {
+        let current_meta = self.token_meta(token_id.clone()).unwrap_or_default();
+        if current_meta.get(STATUS_KEY) != token_meta.get(STATUS_KEY) {
+            runtime::revert(Error::ProtectedMetaKey);
+        }
+        if self.is_admin(self.get_caller()) {
+            return;
+        }
+        let gatekeeper = self.caller_identity();
+        let permissions = current_meta
+            .keys()
+            .chain(token_meta.keys())
+            .filter(|key| current_meta.get(*key) != token_meta.get(*key))
+            .fold(0, |permissions, key| {
+                permissions
+                    | meta_keys::edit_permissions(key, &gatekeeper)
+                        .unwrap_or_revert_with(Error::ProtectedMetaKey)
+            });
+        self.assert_caller_issues_in(self.token_network(token_id), permissions);
+    }
+
+    /// Typed claims only change through `set_typed_claim`, keeping the view in sync.
+    fn assert_typed_claims_unchanged(&self, token_id: &TokenId, token_meta: &Meta) {
+        let typed_claims = self.typed_claims(token_id);
+        if claims_meta_view(&typed_claims)
+            .iter()
+            .any(|(claim, value)| token_meta.get(claim) != Some(value))
+        {
+            runtime::revert(Error::ClaimTypeMismatch);
+        }
+    }
+
+    /// Typed claims may not shadow the reserved metadata keys the contract rewrites.
+    fn assert_claim_name_allowed(&self, claim: &str) {
+        if meta_keys::is_reserved(claim) {
+            runtime::revert(Error::ProtectedMetaKey);
+        }
+    }
+
+    fn assert_caller_mints_meta(&self, token_meta: &Meta) {
+        if self.is_admin(self.get_caller()) {
+            return;
+        }
+        let gatekeeper = self.caller_identity();
+        if !token_meta
+            .keys()
+            .all(|key| meta_keys::may_set_at_mint(key, &gatekeeper))
+        {
+            runtime::revert(Error::ProtectedMetaKey);
+        }
+    }
+
+    fn assert_caller_manages_network(&self, network: NetworkId) {
+        let caller = self.get_caller();
+        if !self.is_admin(caller) && self.network_authority(network) != Some(caller) {
+            runtime::revert(Error::PermissionDenied);
+        }
+    }
+
+    fn burn_expired(&mut self, token_id: TokenId) {
+        let network = self.token_network(&token_id);
+        let (_, _, expiry_removes_token) = self.network_token_policy(network);
+        if !expiry_removes_token {
+            runtime::revert(Error::PermissionDenied);
+        }
+        if self.verify_token(&token_id) != verification::EXPIRED {
+            runtime::revert(Error::TokenNotExpired);
+        }
+        let owner = self.owner_of(token_id.clone()).unwrap_or_revert();
+        self.burn_token(owner, token_id);
+    }
+
+    /// Every mutation reverts once the contract was sunset.
+    fn assert_not_sunset(&self) {
+        if self.successor().is_some() {
+            runtime::revert(Error::Moved);
         }
-        false
     }
 
-    fn assert_authorized_caller(&self) {
+    fn sunset_contract(&mut self, successor: Key, forward_reads: bool) {
+        successor
+            .into_hash()
+            .unwrap_or_revert_with(ApiError::UnexpectedKeyVariant);
+        self.sunset(successor, forward_reads);
+    }
+
+    fn assert_caller_manages_blocklist(&self) {
         let caller = self.get_caller();
-        if !self.is_gatekeeper() && !self.is_admin(caller) {
-            runtime::revert(ApiError::User(20));
+        if !self.is_admin(caller) && !self.is_compliance_officer(caller) {
+            runtime::revert(Error::PermissionDenied);
         }
     }
 }
 
+fn block_time() -> u64 {
+    runtime::get_blocktime().into()
+}
+
+fn page(offset: u64, limit: u64) -> core::ops::Range<u64> {
+    offset..offset.saturating_add(limit.min(MAX_PAGE_SIZE))
+}
+
+/// Answers an account lookup from the successor of a sunset contract.
+fn forward_read<T: CLTyped + FromBytes>(
+    successor: Key,
+    entry_point: &str,
+    account: Key,
+    index: Option<U256>,
+) -> T {
+    runtime::call_versioned_contract(
+        ContractPackageHash::new(successor.into_hash().unwrap_or_revert()),
+        None,
+        entry_point,
+        runtime_args! {
+            "account" => account,
+            "index" => index,
+        },
+    )
+}
+
+fn named_arg<T: CLTyped + FromBytes>(args: &RuntimeArgs, name: &str) -> T {
+    args.get(name)
+        .cloned()
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .into_t()
+        .unwrap_or_revert()
+}
+
+/// Reads a named argument of the deploy, or its default when the deploy leaves it out.
+fn optional_named_arg<T: FromBytes + Default>(name: &str) -> T {
+    let mut arg_size: usize = 0;
+    let ret = unsafe {
+        ext_ffi::casper_get_named_arg_size(
+            name.as_bytes().as_ptr(),
+            name.len(),
+            &mut arg_size as *mut usize,
+        )
+    };
+    match api_error::result_from(ret) {
+        Ok(()) => runtime::get_named_arg(name),
+        Err(ApiError::MissingArgument) => T::default(),
+        Err(error) => runtime::revert(error),
+    }
+}
+
 #[no_mangle]
 fn constructor() {
     let name = runtime::get_named_arg::<String>("name");
@@ -75,8 +1006,11 @@ fn constructor() {
     let meta = runtime::get_named_arg::<Meta>("meta");
     let admin = runtime::get_named_arg::<Key>("admin");
     GatewayToken::default().constructor(name, symbol, meta);
-    GatewayToken::default().add_admin_without_checked(admin);
+    GatewayToken::default().grant_admin_role(admin);
     GatewayToken::default().add_gatekeeper(admin);
+    GatewayToken::default().record_identity(admin);
+    let default_network = (String::from("default"), String::new(), admin);
+    GatewayToken::default().register_network(DEFAULT_NETWORK, default_network);
 }
 
 #[no_mangle]
@@ -136,7 +1070,68 @@ fn token_meta() {
 fn is_kyc_proved() {
     let account = runtime::get_named_arg::<Key>("account");
     let index = runtime::get_named_arg::<Option<U256>>("index");
-    let ret = GatewayToken::default().is_kyc_proved(account, index);
+    if let Some(successor) = GatewayToken::default().read_forwarding_target() {
+        let ret: bool = forward_read(successor, "is_kyc_proved", account, index);
+        runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+    }
+    let reason = GatewayToken::default().verify_any_gateway(account, index);
+    let ret = reason == verification::VERIFIED;
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn verify() {
+    let account = runtime::get_named_arg::<Key>("account");
+    let index = runtime::get_named_arg::<Option<U256>>("index");
+    if let Some(successor) = GatewayToken::default().read_forwarding_target() {
+        let ret: (bool, Reason) = forward_read(successor, "verify", account, index);
+        runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+    }
+    let reason = GatewayToken::default().verify_any_gateway(account, index);
+    let ret = (reason == verification::VERIFIED, reason);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn verify_network() {
+    let account = runtime::get_named_arg::<Key>("account");
+    let network = runtime::get_named_arg::<NetworkId>("network");
+    let reason = GatewayToken::default().federated_verify(account, Some(network), 0);
+    let ret = (reason == verification::VERIFIED, reason);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn federated_verify() {
+    let account = runtime::get_named_arg::<Key>("account");
+    let network = runtime::get_named_arg::<Option<NetworkId>>("network");
+    let depth = runtime::get_named_arg::<u8>("depth");
+    let ret = GatewayToken::default().federated_verify(account, network, depth);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn is_eligible() {
+    let account = runtime::get_named_arg::<Key>("account");
+    let policy_id = runtime::get_named_arg::<PolicyId>("policy_id");
+    let reason = GatewayToken::default().is_eligible(account, policy_id);
+    let ret = (reason == verification::VERIFIED, reason);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn is_kyc_proved_at_level() {
+    let account = runtime::get_named_arg::<Key>("account");
+    let level = runtime::get_named_arg::<Level>("level");
+    let index = runtime::get_named_arg::<Option<U256>>("index");
+    let ret = GatewayToken::default().is_kyc_proved_at_level(account, level, index);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn token_level() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = GatewayToken::default().token_level(&token_id);
     runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
 }
 
@@ -144,10 +1139,13 @@ fn is_kyc_proved() {
 fn set_token_meta() {
     let token_id = runtime::get_named_arg::<TokenId>("token_id");
     let token_meta = runtime::get_named_arg::<Meta>("token_meta");
-    GatewayToken::default().assert_authorized_caller();
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_typed_claims_unchanged(&token_id, &token_meta);
+    GatewayToken::default().assert_caller_edits_meta(&token_id, &token_meta);
     GatewayToken::default()
-        .set_token_meta(token_id, token_meta)
+        .set_token_meta(token_id.clone(), token_meta)
         .unwrap_or_revert();
+    GatewayToken::default().touch_token(&token_id);
 }
 
 #[no_mangle]
@@ -155,35 +1153,42 @@ fn update_token_meta() {
     let token_id = runtime::get_named_arg::<TokenId>("token_id");
     let token_meta_key = runtime::get_named_arg::<String>("token_meta_key");
     let token_meta_value = runtime::get_named_arg::<String>("token_meta_value");
+    GatewayToken::default().assert_not_sunset();
     let mut token_meta = GatewayToken::default()
         .token_meta(token_id.clone())
         .unwrap_or_revert();
-    GatewayToken::default().assert_authorized_caller();
     token_meta.insert(token_meta_key, token_meta_value);
+    GatewayToken::default().assert_typed_claims_unchanged(&token_id, &token_meta);
+    GatewayToken::default().assert_caller_edits_meta(&token_id, &token_meta);
     GatewayToken::default()
-        .set_token_meta(token_id, token_meta)
+        .set_token_meta(token_id.clone(), token_meta)
         .unwrap_or_revert();
+    GatewayToken::default().touch_token(&token_id);
 }
 
 #[no_mangle]
 fn mint() {
     let recipient = runtime::get_named_arg::<Key>("recipient");
     let token_id = runtime::get_named_arg::<Option<TokenId>>("token_id");
-    let token_meta = runtime::get_named_arg::<Meta>("token_meta");
-    GatewayToken::default().assert_caller_is_gatekeeper();
-    GatewayToken::default()
-        .mint(recipient, token_id.map(|x| vec![x]), vec![token_meta])
-        .unwrap_or_revert();
+    let mut token_meta = runtime::get_named_arg::<Meta>("token_meta");
+    let options = MintOptions::from_named_args();
+    token_meta.extend(claims_meta_view(&options.typed_claims));
+    GatewayToken::default().assert_not_sunset();
+    for claim in options.typed_claims.keys() {
+        GatewayToken::default().assert_claim_name_allowed(claim);
+    }
+    GatewayToken::default().assert_caller_issues_in(options.network, MINT);
+    GatewayToken::default().assert_caller_mints_meta(&token_meta);
+    GatewayToken::default().mint_token(recipient, token_id, token_meta, options);
 }
 
 #[no_mangle]
 fn burn() {
     let owner = runtime::get_named_arg::<Key>("owner");
     let token_id = runtime::get_named_arg::<TokenId>("token_id");
-    GatewayToken::default().assert_authorized_caller();
-    GatewayToken::default()
-        .burn_internal(owner, vec![token_id])
-        .unwrap_or_revert();
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_caller_manages_token(&token_id, REVOKE);
+    GatewayToken::default().burn_token(owner, token_id);
 }
 
 #[no_mangle]
@@ -191,262 +1196,2138 @@ fn transfer_from() {
     let owner = runtime::get_named_arg::<Key>("sender");
     let recipient = runtime::get_named_arg::<Key>("recipient");
     let token_ids = runtime::get_named_arg::<Vec<TokenId>>("token_ids");
+    GatewayToken::default().assert_not_sunset();
     GatewayToken::default().assert_caller_is_admin();
-    GatewayToken::default()
-        .transfer_from_internal(owner, recipient, token_ids)
-        .unwrap_or_revert();
+    GatewayToken::default().assert_direct_call_allowed("transfer_from");
+    GatewayToken::default().transfer_tokens(owner, recipient, token_ids);
 }
 
 #[no_mangle]
 fn grant_gatekeeper() {
     let gatekeeper = runtime::get_named_arg::<Key>("gatekeeper");
+    GatewayToken::default().assert_not_sunset();
     GatewayToken::default().assert_caller_is_admin();
-    GatewayToken::default().add_gatekeeper(gatekeeper);
+    GatewayToken::default().assert_direct_call_allowed("grant_gatekeeper");
+    GatewayToken::default().change_role("grant_gatekeeper", gatekeeper);
 }
 
 #[no_mangle]
 fn revoke_gatekeeper() {
     let gatekeeper = runtime::get_named_arg::<Key>("gatekeeper");
+    GatewayToken::default().assert_not_sunset();
     GatewayToken::default().assert_caller_is_admin();
-    GatewayToken::default().revoke_gatekeeper(gatekeeper);
+    GatewayToken::default().change_role("revoke_gatekeeper", gatekeeper);
 }
 
 #[no_mangle]
 fn grant_admin() {
     let admin = runtime::get_named_arg::<Key>("admin");
-    GatewayToken::default().add_admin(admin);
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_caller_is_admin();
+    GatewayToken::default().assert_direct_call_allowed("grant_admin");
+    GatewayToken::default().change_role("grant_admin", admin);
 }
 
 #[no_mangle]
 fn revoke_admin() {
     let admin = runtime::get_named_arg::<Key>("admin");
-    GatewayToken::default().disable_admin(admin);
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_caller_is_admin();
+    GatewayToken::default().assert_direct_call_allowed("revoke_admin");
+    GatewayToken::default().change_role("revoke_admin", admin);
 }
 
 #[no_mangle]
-pub extern "C" fn call() {
-    // Read arguments for the constructor call.
-    let name: String = runtime::get_named_arg("name");
-    let symbol: String = runtime::get_named_arg("symbol");
-    let meta: Meta = runtime::get_named_arg("meta");
-    let admin: Key = runtime::get_named_arg("admin");
-    let contract_name: String = runtime::get_named_arg("contract_name");
+fn emergency_revoke_gatekeeper() {
+    let gatekeeper = runtime::get_named_arg::<Key>("gatekeeper");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_caller_is_admin();
+    GatewayToken::default().revoke_gatekeeper(gatekeeper);
+}
 
-    // Prepare constructor args
-    let constructor_args = runtime_args! {
-        "name" => name,
-        "symbol" => symbol,
-        "meta" => meta,
-        "admin" => admin
-    };
+#[no_mangle]
+fn emergency_revoke_admin() {
+    let admin = runtime::get_named_arg::<Key>("admin");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_caller_is_admin();
+    GatewayToken::default().assert_direct_call_allowed("emergency_revoke_admin");
+    GatewayToken::default().revoke_admin_role(admin);
+}
 
-    let (contract_hash, _) = storage::new_contract(
-        get_entry_points(),
-        None,
-        Some(String::from(&format!(
-            "{}_contract_package_hash",
-            contract_name
-        ))),
-        Some(String::from(&format!("{}_access_token", contract_name))),
-    );
+#[no_mangle]
+fn role_change_delay() {
+    let ret = GatewayToken::default().role_change_delay();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
 
-    let package_hash = ContractPackageHash::new(
-        runtime::get_key(&format!("{}_contract_package_hash", contract_name))
-            .unwrap_or_revert()
-            .into_hash()
-            .unwrap_or_revert(),
-    );
+#[no_mangle]
+fn set_role_change_delay() {
+    let delay = runtime::get_named_arg::<u64>("delay");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_caller_is_admin();
+    GatewayToken::default().assert_direct_call_allowed("set_role_change_delay");
+    GatewayToken::default().change_role_change_delay(delay);
+}
 
-    let constructor_access: URef =
-        storage::create_contract_user_group(package_hash, "constructor", 1, Default::default())
-            .unwrap_or_revert()
-            .pop()
-            .unwrap_or_revert();
+#[no_mangle]
+fn scheduled_role_change() {
+    let change_id = runtime::get_named_arg::<RoleChangeId>("change_id");
+    let ret = GatewayToken::default().scheduled_role_change(change_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
 
-    let _: () = runtime::call_contract(contract_hash, "constructor", constructor_args);
+#[no_mangle]
+fn role_change_proposer() {
+    let change_id = runtime::get_named_arg::<RoleChangeId>("change_id");
+    let ret = GatewayToken::default().role_change_proposer(change_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
 
-    let mut urefs = BTreeSet::new();
-    urefs.insert(constructor_access);
-    storage::remove_contract_user_group_urefs(package_hash, "constructor", urefs)
+#[no_mangle]
+fn scheduled_role_change_delay() {
+    let change_id = runtime::get_named_arg::<RoleChangeId>("change_id");
+    let ret = GatewayToken::default().scheduled_role_change_delay(change_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn execute_role_change() {
+    let change_id = runtime::get_named_arg::<RoleChangeId>("change_id");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_caller_is_admin();
+    GatewayToken::default().execute_role_change(change_id);
+}
+
+#[no_mangle]
+fn cancel_role_change() {
+    let change_id = runtime::get_named_arg::<RoleChangeId>("change_id");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_caller_is_admin();
+    let admin = GatewayToken::default().get_caller();
+    GatewayToken::default().cancel_role_change(change_id, admin);
+}
+
+#[no_mangle]
+fn token_network() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = GatewayToken::default().token_network(&token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn token_identity() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = GatewayToken::default().token_identity(&token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn identity_accounts() {
+    let network = runtime::get_named_arg::<NetworkId>("network");
+    let identity_commitment = runtime::get_named_arg::<Commitment>("identity_commitment");
+    let ret = GatewayToken::default().identity_accounts(network, &identity_commitment);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_network_multiple_accounts() {
+    let network = runtime::get_named_arg::<NetworkId>("network");
+    let allowed = runtime::get_named_arg::<bool>("allowed");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_caller_manages_network(network);
+    GatewayToken::default().set_allows_multiple_accounts(network, allowed);
+}
+
+#[no_mangle]
+fn claim_commitments() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = GatewayToken::default().claim_commitments(&token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_claim_commitment() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let claim = runtime::get_named_arg::<String>("claim");
+    let commitment = runtime::get_named_arg::<Option<ClaimCommitment>>("commitment");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_caller_manages_token(&token_id, EDIT_METADATA);
+    GatewayToken::default()
+        .owner_of(token_id.clone())
         .unwrap_or_revert();
+    GatewayToken::default().set_claim_commitment(&token_id, claim, commitment);
+    GatewayToken::default().touch_token(&token_id);
+}
 
-    runtime::put_key(
-        &format!("{}_contract_hash", contract_name),
-        contract_hash.into(),
-    );
-    runtime::put_key(
-        &format!("{}_contract_hash_wrapped", contract_name),
-        storage::new_uref(contract_hash).into(),
-    );
-    runtime::put_key(
-        &format!("{}_package_hash_wrapped", contract_name),
-        storage::new_uref(package_hash).into(),
-    );
+#[no_mangle]
+fn verify_claim() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let claim = runtime::get_named_arg::<String>("claim");
+    let value = runtime::get_named_arg::<String>("value");
+    let salt = runtime::get_named_arg::<String>("salt");
+    let ret = GatewayToken::default().verify_claim(&token_id, &claim, &value, &salt);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
 }
 
-fn get_entry_points() -> EntryPoints {
-    let mut entry_points = EntryPoints::new();
+#[no_mangle]
+fn set_token_level() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let level = runtime::get_named_arg::<Level>("level");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_caller_manages_token(&token_id, EDIT_METADATA);
+    GatewayToken::default().upgrade_token_level(token_id, level);
+}
+
+#[no_mangle]
+fn set_gatekeeper_level_cap() {
+    let network = runtime::get_named_arg::<NetworkId>("network");
+    let gatekeeper = runtime::get_named_arg::<Key>("gatekeeper");
+    GatewayToken::default().assert_not_sunset();
+    let gatekeeper = GatewayToken::default().key_identity(gatekeeper);
+    let max_level = runtime::get_named_arg::<Option<Level>>("max_level");
+    GatewayToken::default().assert_caller_manages_network(network);
+    GatewayToken::default().set_gatekeeper_level_cap(network, gatekeeper, max_level);
+}
+
+#[no_mangle]
+fn is_blocked() {
+    let account = runtime::get_named_arg::<Key>("account");
+    let ret = GatewayToken::default().is_blocked(account);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn block_accounts() {
+    let accounts = runtime::get_named_arg::<Vec<Key>>("accounts");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_caller_manages_blocklist();
+    GatewayToken::default().block_accounts(accounts);
+}
+
+#[no_mangle]
+fn unblock_accounts() {
+    let accounts = runtime::get_named_arg::<Vec<Key>>("accounts");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_caller_manages_blocklist();
+    GatewayToken::default().unblock_accounts(accounts);
+}
+
+#[no_mangle]
+fn grant_compliance_officer() {
+    let officer = runtime::get_named_arg::<Key>("officer");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_caller_is_admin();
+    GatewayToken::default().add_compliance_officer(officer);
+}
+
+#[no_mangle]
+fn revoke_compliance_officer() {
+    let officer = runtime::get_named_arg::<Key>("officer");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_caller_is_admin();
+    GatewayToken::default().revoke_compliance_officer(officer);
+}
+
+#[no_mangle]
+fn token_jurisdiction() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = GatewayToken::default().token_jurisdiction(&token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_token_jurisdiction() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let jurisdiction = runtime::get_named_arg::<Option<String>>("jurisdiction");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_caller_manages_token(&token_id, EDIT_METADATA);
+    GatewayToken::default()
+        .owner_of(token_id.clone())
+        .unwrap_or_revert();
+    GatewayToken::default().set_token_jurisdiction(&token_id, jurisdiction);
+    GatewayToken::default().touch_token(&token_id);
+}
+
+#[no_mangle]
+fn jurisdiction_policy() {
+    let policy_id = runtime::get_named_arg::<PolicyId>("policy_id");
+    let ret = GatewayToken::default().policy(policy_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn register_jurisdiction_policy() {
+    let allow = runtime::get_named_arg::<bool>("allow");
+    let jurisdictions = runtime::get_named_arg::<Vec<String>>("jurisdictions");
+    GatewayToken::default().assert_not_sunset();
+    let mut gateway_token = GatewayToken::default();
+    let owner = gateway_token.get_caller();
+    let ret = gateway_token.register_policy(owner, allow, jurisdictions);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn update_jurisdiction_policy() {
+    let policy_id = runtime::get_named_arg::<PolicyId>("policy_id");
+    let allow = runtime::get_named_arg::<bool>("allow");
+    let jurisdictions = runtime::get_named_arg::<Vec<String>>("jurisdictions");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().update_policy(policy_id, allow, jurisdictions);
+}
+
+#[no_mangle]
+fn gatekeeper_quota() {
+    let gatekeeper = runtime::get_named_arg::<Key>("gatekeeper");
+    let gatekeeper = GatewayToken::default().key_identity(gatekeeper);
+    let ret = GatewayToken::default().gatekeeper_quota(gatekeeper);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_gatekeeper_quota() {
+    let gatekeeper = runtime::get_named_arg::<Key>("gatekeeper");
+    GatewayToken::default().assert_not_sunset();
+    let gatekeeper = GatewayToken::default().key_identity(gatekeeper);
+    let quota = runtime::get_named_arg::<Option<Quota>>("quota");
+    GatewayToken::default().assert_caller_is_admin();
+    GatewayToken::default().set_gatekeeper_quota(gatekeeper, quota);
+}
+
+#[no_mangle]
+fn gatekeeper_live_tokens() {
+    let gatekeeper = runtime::get_named_arg::<Key>("gatekeeper");
+    let gatekeeper = GatewayToken::default().key_identity(gatekeeper);
+    let ret = GatewayToken::default().gatekeeper_live_tokens(gatekeeper);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn gatekeeper_mints_in_window() {
+    let gatekeeper = runtime::get_named_arg::<Key>("gatekeeper");
+    let gatekeeper = GatewayToken::default().key_identity(gatekeeper);
+    let ret = GatewayToken::default().gatekeeper_mints_in_window(gatekeeper, block_time());
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn token_issuance() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = GatewayToken::default().token_issuance(&token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn propose() {
+    let entry_point = runtime::get_named_arg::<String>("entry_point");
+    let args = runtime::get_named_arg::<Vec<u8>>("args");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_caller_is_admin();
+    let ret = GatewayToken::default().propose_action(entry_point, args);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn approve_proposal() {
+    let proposal_id = runtime::get_named_arg::<ProposalId>("proposal_id");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_caller_is_admin();
+    GatewayToken::default().approve_proposal(proposal_id);
+}
+
+#[no_mangle]
+fn proposal() {
+    let proposal_id = runtime::get_named_arg::<ProposalId>("proposal_id");
+    let ret = GatewayToken::default().proposal(proposal_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn proposal_approvals() {
+    let proposal_id = runtime::get_named_arg::<ProposalId>("proposal_id");
+    let ret = GatewayToken::default().proposal_approvals(proposal_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_multisig_config() {
+    let threshold = runtime::get_named_arg::<u32>("threshold");
+    let proposal_lifetime = runtime::get_named_arg::<u64>("proposal_lifetime");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_caller_is_admin();
+    GatewayToken::default().assert_direct_call_allowed("set_multisig_config");
+    GatewayToken::default().configure_multisig(threshold, proposal_lifetime);
+}
+
+#[no_mangle]
+fn gatekeeper_count() {
+    let ret = GatewayToken::default().gatekeeper_count();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn gatekeeper_at() {
+    let index = runtime::get_named_arg::<u64>("index");
+    let ret = GatewayToken::default().gatekeeper_at(index);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn admin_count() {
+    let ret = GatewayToken::default().admin_count();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn admin_at() {
+    let index = runtime::get_named_arg::<u64>("index");
+    let ret = GatewayToken::default().admin_at(index);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn tokens_of() {
+    let owner = runtime::get_named_arg::<Key>("owner");
+    let offset = runtime::get_named_arg::<u64>("offset");
+    let limit = runtime::get_named_arg::<u64>("limit");
+    let ret = GatewayToken::default().tokens_of(owner, offset, limit);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn tokens() {
+    let offset = runtime::get_named_arg::<u64>("offset");
+    let limit = runtime::get_named_arg::<u64>("limit");
+    let ret = GatewayToken::default().tokens(offset, limit);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn token_count() {
+    let ret = GatewayToken::default().token_count();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn network_info() {
+    let network = runtime::get_named_arg::<NetworkId>("network");
+    let ret = GatewayToken::default().network_info(network);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn register_network() {
+    let network = runtime::get_named_arg::<NetworkId>("network");
+    let info = runtime::get_named_arg::<NetworkInfo>("info");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_caller_is_admin();
+    GatewayToken::default().register_network(network, info);
+}
+
+#[no_mangle]
+fn set_network_info() {
+    let network = runtime::get_named_arg::<NetworkId>("network");
+    let info = runtime::get_named_arg::<NetworkInfo>("info");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_caller_manages_network(network);
+    GatewayToken::default().set_network_info(network, info);
+}
+
+#[no_mangle]
+fn network_token_policy() {
+    let network = runtime::get_named_arg::<NetworkId>("network");
+    let ret = GatewayToken::default().network_token_policy(network);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_network_token_policy() {
+    let network = runtime::get_named_arg::<NetworkId>("network");
+    let policy = runtime::get_named_arg::<TokenPolicy>("policy");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_caller_manages_network(network);
+    GatewayToken::default().set_network_token_policy(network, policy);
+}
+
+#[no_mangle]
+fn network_fee_policy() {
+    let network = runtime::get_named_arg::<NetworkId>("network");
+    let ret = GatewayToken::default().network_fee_policy(network);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_network_fee_policy() {
+    let network = runtime::get_named_arg::<NetworkId>("network");
+    let policy = runtime::get_named_arg::<FeePolicy>("policy");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_caller_manages_network(network);
+    GatewayToken::default().set_network_fee_policy(network, policy);
+}
+
+#[no_mangle]
+fn is_network_gatekeeper() {
+    let network = runtime::get_named_arg::<NetworkId>("network");
+    let gatekeeper = runtime::get_named_arg::<Key>("gatekeeper");
+    let gatekeeper = GatewayToken::default().key_identity(gatekeeper);
+    let ret = GatewayToken::default().is_network_gatekeeper(network, gatekeeper);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn grant_network_gatekeeper() {
+    let network = runtime::get_named_arg::<NetworkId>("network");
+    let gatekeeper = runtime::get_named_arg::<Key>("gatekeeper");
+    GatewayToken::default().assert_not_sunset();
+    let gatekeeper = GatewayToken::default().key_identity(gatekeeper);
+    GatewayToken::default().assert_caller_manages_network(network);
+    GatewayToken::default().add_network_gatekeeper(network, gatekeeper);
+    GatewayToken::default().record_identity(gatekeeper);
+    GatewayToken::default().reinstate(gatekeeper);
+}
+
+#[no_mangle]
+fn revoke_network_gatekeeper() {
+    let network = runtime::get_named_arg::<NetworkId>("network");
+    let gatekeeper = runtime::get_named_arg::<Key>("gatekeeper");
+    GatewayToken::default().assert_not_sunset();
+    let gatekeeper = GatewayToken::default().key_identity(gatekeeper);
+    GatewayToken::default().assert_caller_manages_network(network);
+    GatewayToken::default().revoke_network_gatekeeper(network, gatekeeper);
+}
+
+#[no_mangle]
+fn burn_expired() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().burn_expired(token_id);
+}
+
+#[no_mangle]
+fn gatekeeper_identity() {
+    let key = runtime::get_named_arg::<Key>("key");
+    let ret = GatewayToken::default().gatekeeper_identity(key);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn pending_key_rotation() {
+    let new_key = runtime::get_named_arg::<Key>("new_key");
+    let ret = GatewayToken::default().pending_key_rotation(new_key);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn rotate_gatekeeper_key() {
+    let new_key = runtime::get_named_arg::<Key>("new_key");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().rotate_gatekeeper_key(new_key);
+}
+
+#[no_mangle]
+fn confirm_gatekeeper_key() {
+    let current_key = runtime::get_named_arg::<Key>("current_key");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().confirm_gatekeeper_key(current_key);
+}
+
+#[no_mangle]
+fn resign_gatekeeper() {
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().resign_gatekeeper();
+}
+
+#[no_mangle]
+fn gatekeeper_compromised_since() {
+    let gatekeeper = runtime::get_named_arg::<Key>("gatekeeper");
+    let gatekeeper = GatewayToken::default().key_identity(gatekeeper);
+    let ret = GatewayToken::default().compromised_since(gatekeeper);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn mark_gatekeeper_compromised() {
+    let gatekeeper = runtime::get_named_arg::<Key>("gatekeeper");
+    GatewayToken::default().assert_not_sunset();
+    let gatekeeper = GatewayToken::default().key_identity(gatekeeper);
+    let since = runtime::get_named_arg::<Option<u64>>("since");
+    GatewayToken::default().assert_caller_is_admin();
+    GatewayToken::default().set_compromised_since(gatekeeper, since);
+}
+
+#[no_mangle]
+fn compromised_tokens() {
+    let gatekeeper = runtime::get_named_arg::<Key>("gatekeeper");
+    let gatekeeper = GatewayToken::default().key_identity(gatekeeper);
+    let offset = runtime::get_named_arg::<u64>("offset");
+    let limit = runtime::get_named_arg::<u64>("limit");
+    let ret = GatewayToken::default().compromised_tokens(gatekeeper, offset, limit);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn gatekeeper_suspended_until() {
+    let gatekeeper = runtime::get_named_arg::<Key>("gatekeeper");
+    let gatekeeper = GatewayToken::default().key_identity(gatekeeper);
+    let ret = GatewayToken::default().suspended_until(gatekeeper);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn suspend_gatekeeper() {
+    let gatekeeper = runtime::get_named_arg::<Key>("gatekeeper");
+    GatewayToken::default().assert_not_sunset();
+    let gatekeeper = GatewayToken::default().key_identity(gatekeeper);
+    let until = runtime::get_named_arg::<u64>("until");
+    GatewayToken::default().assert_caller_is_admin();
+    GatewayToken::default().suspend(gatekeeper, until);
+}
+
+#[no_mangle]
+fn lift_gatekeeper_suspension() {
+    let gatekeeper = runtime::get_named_arg::<Key>("gatekeeper");
+    GatewayToken::default().assert_not_sunset();
+    let gatekeeper = GatewayToken::default().key_identity(gatekeeper);
+    GatewayToken::default().assert_caller_is_admin();
+    GatewayToken::default().lift_suspension(gatekeeper);
+}
+
+#[no_mangle]
+fn gatekeeper_permissions() {
+    let gatekeeper = runtime::get_named_arg::<Key>("gatekeeper");
+    let gatekeeper = GatewayToken::default().key_identity(gatekeeper);
+    let ret = GatewayToken::default().gatekeeper_permissions(gatekeeper);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_gatekeeper_permissions() {
+    let gatekeeper = runtime::get_named_arg::<Key>("gatekeeper");
+    GatewayToken::default().assert_not_sunset();
+    let gatekeeper = GatewayToken::default().key_identity(gatekeeper);
+    let permissions = runtime::get_named_arg::<Permissions>("permissions");
+    GatewayToken::default().assert_caller_is_admin();
+    GatewayToken::default().set_gatekeeper_permissions(gatekeeper, permissions);
+}
+
+fn ret_typed_claim<T: CLTyped + ToBytes>(select: fn(ClaimValue) -> Option<T>) {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let claim = runtime::get_named_arg::<String>("claim");
+    let ret = GatewayToken::default()
+        .typed_claim(&token_id, &claim)
+        .map(|value| select(value).unwrap_or_revert_with(Error::ClaimTypeMismatch));
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn claim_u64() {
+    ret_typed_claim(ClaimValue::into_u64);
+}
+
+#[no_mangle]
+fn claim_bool() {
+    ret_typed_claim(ClaimValue::into_bool);
+}
+
+#[no_mangle]
+fn claim_string() {
+    ret_typed_claim(ClaimValue::into_string);
+}
+
+#[no_mangle]
+fn claim_bytes() {
+    ret_typed_claim(ClaimValue::into_bytes);
+}
+
+#[no_mangle]
+fn claim_key() {
+    ret_typed_claim(ClaimValue::into_key);
+}
+
+#[no_mangle]
+fn set_typed_claim() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let claim = runtime::get_named_arg::<String>("claim");
+    let value = runtime::get_named_arg::<Option<CLValue>>("value");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().update_typed_claim(token_id, claim, value);
+}
+
+#[no_mangle]
+fn token_stamps() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = GatewayToken::default().token_stamps(&token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn network_refresh_interval() {
+    let network = runtime::get_named_arg::<NetworkId>("network");
+    let ret = GatewayToken::default().network_refresh_interval(network);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_network_refresh_interval() {
+    let network = runtime::get_named_arg::<NetworkId>("network");
+    let interval = runtime::get_named_arg::<u64>("interval");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_caller_manages_network(network);
+    GatewayToken::default().set_network_refresh_interval(network, interval);
+}
+
+#[no_mangle]
+fn last_refreshed() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = GatewayToken::default().last_refreshed(&token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn needs_refresh() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = GatewayToken::default().needs_refresh(&token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn refresh() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_caller_manages_token(&token_id, REFRESH);
+    GatewayToken::default().refresh_token(token_id);
+}
+
+#[no_mangle]
+fn verify_fresh() {
+    let account = runtime::get_named_arg::<Key>("account");
+    let index = runtime::get_named_arg::<Option<U256>>("index");
+    let reason = GatewayToken::default().verify_fresh(account, index);
+    let ret = (reason == verification::VERIFIED, reason);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn token_actions() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = GatewayToken::default().token_actions(&token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn token_dispute() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let ret = GatewayToken::default().open_dispute(&token_id);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn freeze() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let reason = runtime::get_named_arg::<ActionReason>("reason");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_caller_manages_token(&token_id, FREEZE);
+    GatewayToken::default().freeze_token(token_id, reason);
+}
+
+#[no_mangle]
+fn unfreeze() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_caller_manages_token(&token_id, FREEZE);
+    GatewayToken::default().unfreeze_token(token_id);
+}
+
+#[no_mangle]
+fn revoke() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let reason = runtime::get_named_arg::<ActionReason>("reason");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_caller_manages_token(&token_id, REVOKE);
+    GatewayToken::default().hold_token(token_id, REVOKED_STATUS, "revoke", reason);
+}
+
+#[no_mangle]
+fn dispute() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let evidence_hash = runtime::get_named_arg::<[u8; 32]>("evidence_hash");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().dispute_token(token_id, evidence_hash);
+}
+
+#[no_mangle]
+fn resolve_dispute() {
+    let token_id = runtime::get_named_arg::<TokenId>("token_id");
+    let reinstate = runtime::get_named_arg::<bool>("reinstate");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_caller_is_admin();
+    GatewayToken::default().resolve_dispute(token_id, reinstate);
+}
+
+#[no_mangle]
+fn linked_holder() {
+    let key = runtime::get_named_arg::<Key>("key");
+    let ret = GatewayToken::default().linked_holder(key);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn linked_keys() {
+    let holder = runtime::get_named_arg::<Key>("holder");
+    let ret = GatewayToken::default().linked_keys(holder);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn link_key() {
+    let key = runtime::get_named_arg::<Key>("key");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().link_key(key);
+}
+
+#[no_mangle]
+fn confirm_key_link() {
+    let holder = runtime::get_named_arg::<Key>("holder");
+    GatewayToken::default().assert_not_sunset();
+    let key = GatewayToken::default().get_caller();
+    GatewayToken::default().confirm_link(key, holder);
+}
+
+#[no_mangle]
+fn unlink_key() {
+    let key = runtime::get_named_arg::<Key>("key");
+    GatewayToken::default().assert_not_sunset();
+    let holder = GatewayToken::default().get_caller();
+    GatewayToken::default().unlink(holder, key);
+}
+
+#[no_mangle]
+fn is_gatekeeper_relayer() {
+    let relayer = runtime::get_named_arg::<Key>("relayer");
+    let gatekeeper = runtime::get_named_arg::<Key>("gatekeeper");
+    let gatekeeper = GatewayToken::default().key_identity(gatekeeper);
+    let ret = GatewayToken::default().is_relayer(relayer, gatekeeper);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn set_gatekeeper_relayer() {
+    let relayer = runtime::get_named_arg::<Key>("relayer");
+    let allowed = runtime::get_named_arg::<bool>("allowed");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().set_gatekeeper_relayer(relayer, allowed);
+}
+
+#[no_mangle]
+fn notification_count() {
+    let ret = GatewayToken::default().notification_count();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn notification() {
+    let index = runtime::get_named_arg::<u64>("index");
+    let ret = GatewayToken::default().notification(index);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn subscriber_cursor() {
+    let subscriber = runtime::get_named_arg::<Key>("subscriber");
+    let ret = GatewayToken::default().subscriber_cursor(subscriber);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn subscribe() {
+    let entry_point = runtime::get_named_arg::<String>("entry_point");
+    GatewayToken::default().assert_not_sunset();
+    let subscriber = GatewayToken::default().get_caller();
+    if subscriber.into_hash().is_none() {
+        runtime::revert(Error::PermissionDenied);
+    }
+    GatewayToken::default().subscribe(subscriber, entry_point);
+}
+
+#[no_mangle]
+fn unsubscribe() {
+    GatewayToken::default().assert_not_sunset();
+    let subscriber = GatewayToken::default().get_caller();
+    GatewayToken::default().unsubscribe(subscriber);
+}
+
+#[no_mangle]
+fn remove_subscriber() {
+    let subscriber = runtime::get_named_arg::<Key>("subscriber");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_caller_is_admin();
+    GatewayToken::default().unsubscribe(subscriber);
+}
+
+#[no_mangle]
+fn deliver_notifications() {
+    let subscriber = runtime::get_named_arg::<Key>("subscriber");
+    let limit = runtime::get_named_arg::<u64>("limit");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().deliver_notifications(subscriber, limit);
+}
+
+#[no_mangle]
+fn skip_notifications() {
+    let count = runtime::get_named_arg::<u64>("count");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().skip_notifications(count);
+}
+
+#[no_mangle]
+fn trusted_gateway_count() {
+    let ret = GatewayToken::default().trusted_gateway_count();
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn trusted_gateway_at() {
+    let index = runtime::get_named_arg::<u64>("index");
+    let ret = GatewayToken::default().trusted_gateway_at(index);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn trusted_gateway_networks() {
+    let gateway = runtime::get_named_arg::<Key>("gateway");
+    let ret = GatewayToken::default().gateway_networks(gateway);
+    runtime::ret(CLValue::from_t(ret).unwrap_or_revert());
+}
+
+#[no_mangle]
+fn trust_gateway() {
+    let gateway = runtime::get_named_arg::<Key>("gateway");
+    let networks = runtime::get_named_arg::<NetworkMapping>("networks");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_caller_is_admin();
+    GatewayToken::default().assert_direct_call_allowed("trust_gateway");
+    GatewayToken::default().trust_external_gateway(gateway, networks);
+}
+
+#[no_mangle]
+fn distrust_gateway() {
+    let gateway = runtime::get_named_arg::<Key>("gateway");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_caller_is_admin();
+    GatewayToken::default().distrust_gateway(gateway);
+}
+
+#[no_mangle]
+fn sunset() {
+    let successor = runtime::get_named_arg::<Key>("successor");
+    let forward_reads = runtime::get_named_arg::<bool>("forward_reads");
+    GatewayToken::default().assert_not_sunset();
+    GatewayToken::default().assert_caller_is_admin();
+    GatewayToken::default().assert_direct_call_allowed("sunset");
+    GatewayToken::default().sunset_contract(successor, forward_reads);
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    // Read arguments for the constructor call.
+    let name: String = runtime::get_named_arg("name");
+    let symbol: String = runtime::get_named_arg("symbol");
+    let meta: Meta = runtime::get_named_arg("meta");
+    let admin: Key = runtime::get_named_arg("admin");
+    let contract_name: String = runtime::get_named_arg("contract_name");
+
+    // Prepare constructor args
+    let constructor_args = runtime_args! {
+        "name" => name,
+        "symbol" => symbol,
+        "meta" => meta,
+        "admin" => admin
+    };
+
+    let (contract_hash, _) = storage::new_contract(
+        get_entry_points(),
+        None,
+        Some(String::from(&format!(
+            "{}_contract_package_hash",
+            contract_name
+        ))),
+        Some(String::from(&format!("{}_access_token", contract_name))),
+    );
+
+    let package_hash = ContractPackageHash::new(
+        runtime::get_key(&format!("{}_contract_package_hash", contract_name))
+            .unwrap_or_revert()
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+
+    let constructor_access: URef =
+        storage::create_contract_user_group(package_hash, "constructor", 1, Default::default())
+            .unwrap_or_revert()
+            .pop()
+            .unwrap_or_revert();
+
+    let _: () = runtime::call_contract(contract_hash, "constructor", constructor_args);
+
+    let mut urefs = BTreeSet::new();
+    urefs.insert(constructor_access);
+    storage::remove_contract_user_group_urefs(package_hash, "constructor", urefs)
+        .unwrap_or_revert();
+
+    runtime::put_key(
+        &format!("{}_contract_hash", contract_name),
+        contract_hash.into(),
+    );
+    runtime::put_key(
+        &format!("{}_contract_hash_wrapped", contract_name),
+        storage::new_uref(contract_hash).into(),
+    );
+    runtime::put_key(
+        &format!("{}_package_hash_wrapped", contract_name),
+        storage::new_uref(package_hash).into(),
+    );
+}
+
+fn get_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        "constructor",
+        vec![
+            Parameter::new("name", String::cl_type()),
+            Parameter::new("symbol", String::cl_type()),
+            Parameter::new("meta", Meta::cl_type()),
+            Parameter::new("admin", Key::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Groups(vec![Group::new("constructor")]),
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "name",
+        vec![],
+        String::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "symbol",
+        vec![],
+        String::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "meta",
+        vec![],
+        Meta::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "total_supply",
+        vec![],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "balance_of",
+        vec![Parameter::new("owner", Key::cl_type())],
+        U256::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "owner_of",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::Option(Box::new(CLType::Key)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "is_kyc_proved",
+        vec![
+            Parameter::new("account", Key::cl_type()),
+            Parameter::new("index", CLType::Option(Box::new(U256::cl_type()))),
+        ],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "verify",
+        vec![
+            Parameter::new("account", Key::cl_type()),
+            Parameter::new("index", CLType::Option(Box::new(U256::cl_type()))),
+        ],
+        <(bool, Reason)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "is_eligible",
+        vec![
+            Parameter::new("account", Key::cl_type()),
+            Parameter::new("policy_id", PolicyId::cl_type()),
+        ],
+        <(bool, Reason)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "is_kyc_proved_at_level",
+        vec![
+            Parameter::new("account", Key::cl_type()),
+            Parameter::new("level", Level::cl_type()),
+            Parameter::new("index", CLType::Option(Box::new(U256::cl_type()))),
+        ],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "token_level",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        Level::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "token_meta",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        Meta::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_token_meta",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("token_meta", Meta::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "update_token_meta",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("token_meta_key", String::cl_type()),
+            Parameter::new("token_meta_value", String::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "mint",
+        vec![
+            Parameter::new("recipient", Key::cl_type()),
+            Parameter::new(
+                "token_ids",
+                CLType::Option(Box::new(CLType::List(Box::new(TokenId::cl_type())))),
+            ),
+            Parameter::new("token_metas", CLType::List(Box::new(Meta::cl_type()))),
+            Parameter::new("network", NetworkId::cl_type()),
+            Parameter::new("level", Level::cl_type()),
+            Parameter::new("identity_commitment", Commitment::cl_type()),
+            Parameter::new("claim_commitments", ClaimCommitments::cl_type()),
+            Parameter::new("jurisdiction", CLType::Option(Box::new(String::cl_type()))),
+            Parameter::new("typed_claims", TypedClaims::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "burn",
+        vec![
+            Parameter::new("owner", Key::cl_type()),
+            Parameter::new("token_ids", CLType::List(Box::new(TokenId::cl_type()))),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "transfer_from",
+        vec![
+            Parameter::new("sender", Key::cl_type()),
+            Parameter::new("recipient", Key::cl_type()),
+            Parameter::new("token_ids", CLType::List(Box::new(TokenId::cl_type()))),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_token_by_index",
+        vec![
+            Parameter::new("owner", Key::cl_type()),
+            Parameter::new("index", U256::cl_type()),
+        ],
+        CLType::Option(Box::new(TokenId::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "grant_gatekeeper",
+        vec![Parameter::new("gatekeeper", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "revoke_gatekeeper",
+        vec![Parameter::new("gatekeeper", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "grant_admin",
+        vec![Parameter::new("admin", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "revoke_admin",
+        vec![Parameter::new("admin", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "token_network",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        NetworkId::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "token_identity",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::Option(Box::new(Commitment::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "identity_accounts",
+        vec![
+            Parameter::new("network", NetworkId::cl_type()),
+            Parameter::new("identity_commitment", Commitment::cl_type()),
+        ],
+        CLType::List(Box::new(Key::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_network_multiple_accounts",
+        vec![
+            Parameter::new("network", NetworkId::cl_type()),
+            Parameter::new("allowed", bool::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "claim_commitments",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        ClaimCommitments::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_claim_commitment",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("claim", String::cl_type()),
+            Parameter::new(
+                "commitment",
+                CLType::Option(Box::new(ClaimCommitment::cl_type())),
+            ),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "verify_claim",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("claim", String::cl_type()),
+            Parameter::new("value", String::cl_type()),
+            Parameter::new("salt", String::cl_type()),
+        ],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_token_level",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("level", Level::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_gatekeeper_level_cap",
+        vec![
+            Parameter::new("network", NetworkId::cl_type()),
+            Parameter::new("gatekeeper", Key::cl_type()),
+            Parameter::new("max_level", CLType::Option(Box::new(Level::cl_type()))),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "is_blocked",
+        vec![Parameter::new("account", Key::cl_type())],
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "block_accounts",
+        vec![Parameter::new(
+            "accounts",
+            CLType::List(Box::new(Key::cl_type())),
+        )],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "unblock_accounts",
+        vec![Parameter::new(
+            "accounts",
+            CLType::List(Box::new(Key::cl_type())),
+        )],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "grant_compliance_officer",
+        vec![Parameter::new("officer", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "revoke_compliance_officer",
+        vec![Parameter::new("officer", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "token_jurisdiction",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::Option(Box::new(String::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_token_jurisdiction",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("jurisdiction", CLType::Option(Box::new(String::cl_type()))),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "jurisdiction_policy",
+        vec![Parameter::new("policy_id", PolicyId::cl_type())],
+        CLType::Option(Box::new(Policy::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "register_jurisdiction_policy",
+        vec![
+            Parameter::new("allow", bool::cl_type()),
+            Parameter::new("jurisdictions", CLType::List(Box::new(String::cl_type()))),
+        ],
+        PolicyId::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "update_jurisdiction_policy",
+        vec![
+            Parameter::new("policy_id", PolicyId::cl_type()),
+            Parameter::new("allow", bool::cl_type()),
+            Parameter::new("jurisdictions", CLType::List(Box::new(String::cl_type()))),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "gatekeeper_quota",
+        vec![Parameter::new("gatekeeper", Key::cl_type())],
+        CLType::Option(Box::new(Quota::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_gatekeeper_quota",
+        vec![
+            Parameter::new("gatekeeper", Key::cl_type()),
+            Parameter::new("quota", CLType::Option(Box::new(Quota::cl_type()))),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "gatekeeper_live_tokens",
+        vec![Parameter::new("gatekeeper", Key::cl_type())],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "gatekeeper_mints_in_window",
+        vec![Parameter::new("gatekeeper", Key::cl_type())],
+        u32::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "token_issuance",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::Option(Box::new(Issuance::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "propose",
+        vec![
+            Parameter::new("entry_point", String::cl_type()),
+            Parameter::new("args", CLType::List(Box::new(CLType::U8))),
+        ],
+        ProposalId::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "approve_proposal",
+        vec![Parameter::new("proposal_id", ProposalId::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "proposal",
+        vec![Parameter::new("proposal_id", ProposalId::cl_type())],
+        CLType::Option(Box::new(Proposal::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "proposal_approvals",
+        vec![Parameter::new("proposal_id", ProposalId::cl_type())],
+        CLType::List(Box::new(CLType::Key)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_multisig_config",
+        vec![
+            Parameter::new("threshold", u32::cl_type()),
+            Parameter::new("proposal_lifetime", u64::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "emergency_revoke_gatekeeper",
+        vec![Parameter::new("gatekeeper", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "emergency_revoke_admin",
+        vec![Parameter::new("admin", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "role_change_delay",
+        vec![],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_role_change_delay",
+        vec![Parameter::new("delay", u64::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "scheduled_role_change",
+        vec![Parameter::new("change_id", RoleChangeId::cl_type())],
+        CLType::Option(Box::new(RoleChange::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "role_change_proposer",
+        vec![Parameter::new("change_id", RoleChangeId::cl_type())],
+        CLType::Option(Box::new(Key::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "scheduled_role_change_delay",
+        vec![Parameter::new("change_id", RoleChangeId::cl_type())],
+        CLType::Option(Box::new(u64::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "execute_role_change",
+        vec![Parameter::new("change_id", RoleChangeId::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "cancel_role_change",
+        vec![Parameter::new("change_id", RoleChangeId::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "gatekeeper_count",
+        vec![],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "gatekeeper_at",
+        vec![Parameter::new("index", u64::cl_type())],
+        CLType::Option(Box::new(CLType::Key)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "admin_count",
+        vec![],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "admin_at",
+        vec![Parameter::new("index", u64::cl_type())],
+        CLType::Option(Box::new(CLType::Key)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "tokens_of",
+        vec![
+            Parameter::new("owner", Key::cl_type()),
+            Parameter::new("offset", u64::cl_type()),
+            Parameter::new("limit", u64::cl_type()),
+        ],
+        Vec::<(TokenId, TokenSummary)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "tokens",
+        vec![
+            Parameter::new("offset", u64::cl_type()),
+            Parameter::new("limit", u64::cl_type()),
+        ],
+        Vec::<(TokenId, TokenSummary)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "token_count",
+        vec![],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "network_info",
+        vec![Parameter::new("network", NetworkId::cl_type())],
+        CLType::Option(Box::new(NetworkInfo::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "register_network",
+        vec![
+            Parameter::new("network", NetworkId::cl_type()),
+            Parameter::new("info", NetworkInfo::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_network_info",
+        vec![
+            Parameter::new("network", NetworkId::cl_type()),
+            Parameter::new("info", NetworkInfo::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "network_token_policy",
+        vec![Parameter::new("network", NetworkId::cl_type())],
+        TokenPolicy::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_network_token_policy",
+        vec![
+            Parameter::new("network", NetworkId::cl_type()),
+            Parameter::new("policy", TokenPolicy::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "network_fee_policy",
+        vec![Parameter::new("network", NetworkId::cl_type())],
+        FeePolicy::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_network_fee_policy",
+        vec![
+            Parameter::new("network", NetworkId::cl_type()),
+            Parameter::new("policy", FeePolicy::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "is_network_gatekeeper",
+        vec![
+            Parameter::new("network", NetworkId::cl_type()),
+            Parameter::new("gatekeeper", Key::cl_type()),
+        ],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "grant_network_gatekeeper",
+        vec![
+            Parameter::new("network", NetworkId::cl_type()),
+            Parameter::new("gatekeeper", Key::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "revoke_network_gatekeeper",
+        vec![
+            Parameter::new("network", NetworkId::cl_type()),
+            Parameter::new("gatekeeper", Key::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "burn_expired",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "gatekeeper_identity",
+        vec![Parameter::new("key", Key::cl_type())],
+        CLType::Option(Box::new(CLType::Key)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "pending_key_rotation",
+        vec![Parameter::new("new_key", Key::cl_type())],
+        CLType::Option(Box::new(CLType::Key)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "rotate_gatekeeper_key",
+        vec![Parameter::new("new_key", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "confirm_gatekeeper_key",
+        vec![Parameter::new("current_key", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "resign_gatekeeper",
+        vec![],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
     entry_points.add_entry_point(EntryPoint::new(
-        "constructor",
+        "gatekeeper_compromised_since",
+        vec![Parameter::new("gatekeeper", Key::cl_type())],
+        CLType::Option(Box::new(u64::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "mark_gatekeeper_compromised",
         vec![
-            Parameter::new("name", String::cl_type()),
-            Parameter::new("symbol", String::cl_type()),
-            Parameter::new("meta", Meta::cl_type()),
-            Parameter::new("admin", Key::cl_type()),
+            Parameter::new("gatekeeper", Key::cl_type()),
+            Parameter::new("since", CLType::Option(Box::new(u64::cl_type()))),
         ],
         <()>::cl_type(),
-        EntryPointAccess::Groups(vec![Group::new("constructor")]),
+        EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "name",
-        vec![],
-        String::cl_type(),
+        "compromised_tokens",
+        vec![
+            Parameter::new("gatekeeper", Key::cl_type()),
+            Parameter::new("offset", u64::cl_type()),
+            Parameter::new("limit", u64::cl_type()),
+        ],
+        CLType::List(Box::new(TokenId::cl_type())),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "symbol",
-        vec![],
-        String::cl_type(),
+        "gatekeeper_suspended_until",
+        vec![Parameter::new("gatekeeper", Key::cl_type())],
+        CLType::Option(Box::new(u64::cl_type())),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "meta",
-        vec![],
-        Meta::cl_type(),
+        "suspend_gatekeeper",
+        vec![
+            Parameter::new("gatekeeper", Key::cl_type()),
+            Parameter::new("until", u64::cl_type()),
+        ],
+        <()>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "total_supply",
-        vec![],
-        U256::cl_type(),
+        "lift_gatekeeper_suspension",
+        vec![Parameter::new("gatekeeper", Key::cl_type())],
+        <()>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "balance_of",
-        vec![Parameter::new("owner", Key::cl_type())],
-        U256::cl_type(),
+        "gatekeeper_permissions",
+        vec![Parameter::new("gatekeeper", Key::cl_type())],
+        Permissions::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "owner_of",
+        "set_gatekeeper_permissions",
+        vec![
+            Parameter::new("gatekeeper", Key::cl_type()),
+            Parameter::new("permissions", Permissions::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "claim_u64",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("claim", String::cl_type()),
+        ],
+        CLType::Option(Box::new(u64::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "claim_bool",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("claim", String::cl_type()),
+        ],
+        CLType::Option(Box::new(bool::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "claim_string",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("claim", String::cl_type()),
+        ],
+        CLType::Option(Box::new(String::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "claim_bytes",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("claim", String::cl_type()),
+        ],
+        CLType::Option(Box::new(Vec::<u8>::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "claim_key",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("claim", String::cl_type()),
+        ],
+        CLType::Option(Box::new(Key::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_typed_claim",
+        vec![
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("claim", String::cl_type()),
+            Parameter::new("value", CLType::Option(Box::new(CLType::Any))),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "token_stamps",
         vec![Parameter::new("token_id", TokenId::cl_type())],
-        CLType::Option(Box::new(CLType::Key)),
+        CLType::Option(Box::new(TokenStamps::cl_type())),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "is_kyc_proved",
+        "network_refresh_interval",
+        vec![Parameter::new("network", NetworkId::cl_type())],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_network_refresh_interval",
+        vec![
+            Parameter::new("network", NetworkId::cl_type()),
+            Parameter::new("interval", u64::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "last_refreshed",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::Option(Box::new(u64::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "needs_refresh",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "refresh",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "verify_fresh",
         vec![
             Parameter::new("account", Key::cl_type()),
             Parameter::new("index", CLType::Option(Box::new(U256::cl_type()))),
         ],
-        CLType::Bool,
+        <(bool, Reason)>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "token_meta",
+        "token_actions",
         vec![Parameter::new("token_id", TokenId::cl_type())],
-        Meta::cl_type(),
+        CLType::List(Box::new(TokenAction::cl_type())),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "set_token_meta",
+        "token_dispute",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        CLType::Option(Box::new(Dispute::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "freeze",
         vec![
             Parameter::new("token_id", TokenId::cl_type()),
-            Parameter::new("token_meta", Meta::cl_type()),
+            Parameter::new("reason", ActionReason::cl_type()),
         ],
         <()>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "update_token_meta",
+        "unfreeze",
+        vec![Parameter::new("token_id", TokenId::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "revoke",
         vec![
             Parameter::new("token_id", TokenId::cl_type()),
-            Parameter::new("token_meta_key", String::cl_type()),
-            Parameter::new("token_meta_value", String::cl_type()),
+            Parameter::new("reason", ActionReason::cl_type()),
         ],
         <()>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "mint",
+        "dispute",
         vec![
-            Parameter::new("recipient", Key::cl_type()),
-            Parameter::new(
-                "token_ids",
-                CLType::Option(Box::new(CLType::List(Box::new(TokenId::cl_type())))),
-            ),
-            Parameter::new("token_metas", CLType::List(Box::new(Meta::cl_type()))),
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("evidence_hash", <[u8; 32]>::cl_type()),
         ],
         <()>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "burn",
+        "resolve_dispute",
         vec![
-            Parameter::new("owner", Key::cl_type()),
-            Parameter::new("token_ids", CLType::List(Box::new(TokenId::cl_type()))),
+            Parameter::new("token_id", TokenId::cl_type()),
+            Parameter::new("reinstate", bool::cl_type()),
         ],
         <()>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "transfer_from",
+        "linked_holder",
+        vec![Parameter::new("key", Key::cl_type())],
+        CLType::Option(Box::new(Key::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "linked_keys",
+        vec![Parameter::new("holder", Key::cl_type())],
+        CLType::List(Box::new(Key::cl_type())),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "link_key",
+        vec![Parameter::new("key", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "confirm_key_link",
+        vec![Parameter::new("holder", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "unlink_key",
+        vec![Parameter::new("key", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "is_gatekeeper_relayer",
         vec![
-            Parameter::new("sender", Key::cl_type()),
-            Parameter::new("recipient", Key::cl_type()),
-            Parameter::new("token_ids", CLType::List(Box::new(TokenId::cl_type()))),
+            Parameter::new("relayer", Key::cl_type()),
+            Parameter::new("gatekeeper", Key::cl_type()),
+        ],
+        bool::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_gatekeeper_relayer",
+        vec![
+            Parameter::new("relayer", Key::cl_type()),
+            Parameter::new("allowed", bool::cl_type()),
         ],
         <()>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "get_token_by_index",
+        "notification_count",
+        vec![],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "notification",
+        vec![Parameter::new("index", u64::cl_type())],
+        Option::<Notification>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "subscriber_cursor",
+        vec![Parameter::new("subscriber", Key::cl_type())],
+        Option::<u64>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "subscribe",
+        vec![Parameter::new("entry_point", String::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "unsubscribe",
+        vec![],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "remove_subscriber",
+        vec![Parameter::new("subscriber", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "deliver_notifications",
         vec![
-            Parameter::new("owner", Key::cl_type()),
-            Parameter::new("index", U256::cl_type()),
+            Parameter::new("subscriber", Key::cl_type()),
+            Parameter::new("limit", u64::cl_type()),
         ],
-        CLType::Option(Box::new(TokenId::cl_type())),
+        <()>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "grant_gatekeeper",
-        vec![Parameter::new("gatekeeper", Key::cl_type())],
+        "skip_notifications",
+        vec![Parameter::new("count", u64::cl_type())],
         <()>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "revoke_gatekeeper",
-        vec![Parameter::new("gatekeeper", Key::cl_type())],
+        "verify_network",
+        vec![
+            Parameter::new("account", Key::cl_type()),
+            Parameter::new("network", NetworkId::cl_type()),
+        ],
+        <(bool, Reason)>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "federated_verify",
+        vec![
+            Parameter::new("account", Key::cl_type()),
+            Parameter::new("network", Option::<NetworkId>::cl_type()),
+            Parameter::new("depth", u8::cl_type()),
+        ],
+        Reason::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "trusted_gateway_count",
+        vec![],
+        u64::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "trusted_gateway_at",
+        vec![Parameter::new("index", u64::cl_type())],
+        Option::<Key>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "trusted_gateway_networks",
+        vec![Parameter::new("gateway", Key::cl_type())],
+        NetworkMapping::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "trust_gateway",
+        vec![
+            Parameter::new("gateway", Key::cl_type()),
+            Parameter::new("networks", NetworkMapping::cl_type()),
+        ],
         <()>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "grant_admin",
-        vec![Parameter::new("admin", Key::cl_type())],
+        "distrust_gateway",
+        vec![Parameter::new("gateway", Key::cl_type())],
         <()>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     ));
     entry_points.add_entry_point(EntryPoint::new(
-        "revoke_admin",
-        vec![Parameter::new("admin", Key::cl_type())],
+        "sunset",
+        vec![
+            Parameter::new("successor", Key::cl_type()),
+            Parameter::new("forward_reads", bool::cl_type()),
+        ],
         <()>::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
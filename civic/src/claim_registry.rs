@@ -0,0 +1,83 @@
+use alloc::{collections::BTreeMap, string::String};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use cep47::{
+    contract_utils::{ContractContext, ContractStorage, Dict},
+    TokenId,
+};
+use civic_claims::{claim_preimage, ClaimCommitment};
+
+pub type ClaimCommitments = BTreeMap<String, ClaimCommitment>;
+
+const CLAIM_COMMITMENTS_DICT: &str = "claim_commitments";
+
+pub trait ClaimRegistry<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(&mut self) {
+        Claims::init();
+    }
+
+    fn claim_commitments(&self, token_id: &TokenId) -> ClaimCommitments {
+        Claims::instance().get(token_id)
+    }
+
+    fn set_claim_commitments(&mut self, token_id: &TokenId, commitments: ClaimCommitments) {
+        Claims::instance().set(token_id, commitments);
+    }
+
+    fn set_claim_commitment(
+        &mut self,
+        token_id: &TokenId,
+        claim: String,
+        commitment: Option<ClaimCommitment>,
+    ) {
+        let claims = Claims::instance();
+        let mut commitments = claims.get(token_id);
+        match commitment {
+            Some(commitment) => commitments.insert(claim, commitment),
+            None => commitments.remove(&claim),
+        };
+        claims.set(token_id, commitments);
+    }
+
+    fn remove_claim_commitments(&mut self, token_id: &TokenId) {
+        Claims::instance().set(token_id, ClaimCommitments::new());
+    }
+
+    fn verify_claim(&self, token_id: &TokenId, claim: &str, value: &str, salt: &str) -> bool {
+        match Claims::instance().get(token_id).get(claim) {
+            Some(commitment) => runtime::blake2b(claim_preimage(claim, value, salt)) == *commitment,
+            None => false,
+        }
+    }
+}
+
+struct Claims {
+    dict: Dict,
+}
+
+impl Claims {
+    pub fn instance() -> Claims {
+        Claims {
+            dict: Dict::instance(CLAIM_COMMITMENTS_DICT),
+        }
+    }
+
+    pub fn init() {
+        storage::new_dictionary(CLAIM_COMMITMENTS_DICT).unwrap_or_revert();
+    }
+
+    pub fn get(&self, token_id: &TokenId) -> ClaimCommitments {
+        self.dict.get(token_id).unwrap_or_default()
+    }
+
+    pub fn set(&self, token_id: &TokenId, commitments: ClaimCommitments) {
+        if commitments.is_empty() {
+            self.dict.remove::<ClaimCommitments>(token_id);
+        } else {
+            self.dict.set(token_id, commitments);
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{CLType, CLValue, Key};
+use cep47::{
+    contract_utils::{ContractContext, ContractStorage, Dict},
+    Meta, TokenId,
+};
+
+use crate::error::Error;
+
+/// Typed claims of a token, each kept as the `CLValue` it was set with.
+pub type TypedClaims = BTreeMap<String, CLValue>;
+
+const TYPED_CLAIMS_DICT: &str = "typed_claims";
+
+/// Value types a typed claim can hold.
+pub enum ClaimValue {
+    U64(u64),
+    Bool(bool),
+    String(String),
+    Bytes(Vec<u8>),
+    Key(Key),
+}
+
+impl ClaimValue {
+    pub fn from_cl_value(value: &CLValue) -> Option<ClaimValue> {
+        let value = value.clone();
+        match value.cl_type() {
+            CLType::U64 => value.into_t().ok().map(ClaimValue::U64),
+            CLType::Bool => value.into_t().ok().map(ClaimValue::Bool),
+            CLType::String => value.into_t().ok().map(ClaimValue::String),
+            CLType::List(item) if **item == CLType::U8 => {
+                value.into_t().ok().map(ClaimValue::Bytes)
+            }
+            CLType::Key => value.into_t().ok().map(ClaimValue::Key),
+            _ => None,
+        }
+    }
+
+    /// Representation of the value in the stringified `token_meta` view.
+    pub fn to_meta_string(&self) -> String {
+        match self {
+            ClaimValue::U64(value) => value.to_string(),
+            ClaimValue::Bool(value) => value.to_string(),
+            ClaimValue::String(value) => value.clone(),
+            ClaimValue::Bytes(value) => hex::encode(value),
+            ClaimValue::Key(value) => value.to_formatted_string(),
+        }
+    }
+
+    pub fn into_u64(self) -> Option<u64> {
+        match self {
+            ClaimValue::U64(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn into_bool(self) -> Option<bool> {
+        match self {
+            ClaimValue::Bool(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn into_string(self) -> Option<String> {
+        match self {
+            ClaimValue::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn into_bytes(self) -> Option<Vec<u8>> {
+        match self {
+            ClaimValue::Bytes(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn into_key(self) -> Option<Key> {
+        match self {
+            ClaimValue::Key(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+/// Stringified view of typed claims merged into `token_meta`, reverting on
+/// values of an unsupported type.
+pub fn claims_meta_view(claims: &TypedClaims) -> Meta {
+    claims
+        .iter()
+        .map(|(claim, value)| (claim.clone(), claim_meta_string(value)))
+        .collect()
+}
+
+pub fn claim_meta_string(value: &CLValue) -> String {
+    ClaimValue::from_cl_value(value)
+        .unwrap_or_revert_with(Error::UnsupportedClaimType)
+        .to_meta_string()
+}
+
+pub trait ClaimStore<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(&mut self) {
+        TypedClaimValues::init();
+    }
+
+    fn typed_claims(&self, token_id: &TokenId) -> TypedClaims {
+        TypedClaimValues::instance().get(token_id)
+    }
+
+    fn typed_claim(&self, token_id: &TokenId, claim: &str) -> Option<ClaimValue> {
+        self.typed_claims(token_id)
+            .get(claim)
+            .and_then(ClaimValue::from_cl_value)
+    }
+
+    fn set_typed_claims(&mut self, token_id: &TokenId, claims: TypedClaims) {
+        TypedClaimValues::instance().set(token_id, claims);
+    }
+
+    fn set_typed_claim(&mut self, token_id: &TokenId, claim: String, value: Option<CLValue>) {
+        let typed_claims = TypedClaimValues::instance();
+        let mut claims = typed_claims.get(token_id);
+        match value {
+            Some(value) => claims.insert(claim, value),
+            None => claims.remove(&claim),
+        };
+        typed_claims.set(token_id, claims);
+    }
+
+    fn remove_typed_claims(&mut self, token_id: &TokenId) {
+        TypedClaimValues::instance().set(token_id, TypedClaims::new());
+    }
+}
+
+struct TypedClaimValues {
+    dict: Dict,
+}
+
+impl TypedClaimValues {
+    pub fn instance() -> TypedClaimValues {
+        TypedClaimValues {
+            dict: Dict::instance(TYPED_CLAIMS_DICT),
+        }
+    }
+
+    pub fn init() {
+        storage::new_dictionary(TYPED_CLAIMS_DICT).unwrap_or_revert();
+    }
+
+    pub fn get(&self, token_id: &TokenId) -> TypedClaims {
+        self.dict.get(token_id).unwrap_or_default()
+    }
+
+    pub fn set(&self, token_id: &TokenId, claims: TypedClaims) {
+        if claims.is_empty() {
+            self.dict.remove::<TypedClaims>(token_id);
+        } else {
+            self.dict.set(token_id, claims);
+        }
+    }
+}
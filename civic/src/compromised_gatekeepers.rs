@@ -0,0 +1,56 @@
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::Key;
+use cep47::contract_utils::{ContractContext, ContractStorage, Dict};
+
+const COMPROMISED_DICT: &str = "compromised_gatekeepers";
+
+/// Gatekeeper identities known to be compromised, with the block time from which
+/// the tokens they issued can no longer be trusted.
+pub trait CompromisedGatekeepers<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(&mut self) {
+        Compromised::init();
+    }
+
+    fn compromised_since(&self, gatekeeper: Key) -> Option<u64> {
+        Compromised::instance().get(&gatekeeper)
+    }
+
+    fn set_compromised_since(&mut self, gatekeeper: Key, since: Option<u64>) {
+        Compromised::instance().set(&gatekeeper, since);
+    }
+
+    /// Whether a token issued by the gatekeeper at the block time is affected.
+    fn is_compromised_issuance(&self, gatekeeper: Key, issued_at: u64) -> bool {
+        match self.compromised_since(gatekeeper) {
+            Some(since) => issued_at >= since,
+            None => false,
+        }
+    }
+}
+
+struct Compromised {
+    dict: Dict,
+}
+
+impl Compromised {
+    pub fn instance() -> Compromised {
+        Compromised {
+            dict: Dict::instance(COMPROMISED_DICT),
+        }
+    }
+
+    pub fn init() {
+        storage::new_dictionary(COMPROMISED_DICT).unwrap_or_revert();
+    }
+
+    pub fn get(&self, gatekeeper: &Key) -> Option<u64> {
+        self.dict.get_by_key(gatekeeper)
+    }
+
+    pub fn set(&self, gatekeeper: &Key, since: Option<u64>) {
+        match since {
+            Some(since) => self.dict.set_by_key(gatekeeper, since),
+            None => self.dict.remove_by_key::<u64>(gatekeeper),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use casper_types::ApiError;
+
+#[repr(u16)]
+pub enum Error {
+    PermissionDenied = 20,
+    IdentityAlreadyRegistered = 21,
+    LevelNotAllowed = 22,
+    UnknownPolicy = 23,
+    QuotaExceeded = 24,
+    UnknownProposal = 25,
+    ProposalExpired = 26,
+    AlreadyApproved = 27,
+    ApprovalRequired = 28,
+    UnknownAction = 29,
+    UnknownRoleChange = 30,
+    RoleChangeLocked = 31,
+    UnknownNetwork = 32,
+    NetworkAlreadyRegistered = 33,
+    TokenNotExpired = 34,
+    KeyInUse = 35,
+    UnknownKeyRotation = 36,
+    ProtectedMetaKey = 37,
+    UnsupportedClaimType = 38,
+    ClaimTypeMismatch = 39,
+    TokenNotRefreshable = 40,
+    TokenNotHeld = 41,
+    DisputeOpen = 42,
+    UnknownDispute = 43,
+    UnknownLink = 44,
+    UnknownSubscriber = 45,
+    Moved = 46,
+    InvalidThreshold = 47,
+    TooManyGateways = 48,
+    TokenRevoked = 49,
+}
+
+impl From<Error> for ApiError {
+    fn from(error: Error) -> ApiError {
+        ApiError::User(error as u16)
+    }
+}
@@ -1,34 +1,53 @@
-use casper_contract::{
-    contract_api::{runtime, storage},
-    unwrap_or_revert::UnwrapOrRevert,
-};
-use casper_types::{ApiError, Key};
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::Key;
 use cep47::contract_utils::{ContractContext, ContractStorage, Dict};
 
+use crate::indexed_set::IndexedSet;
+
 const GATEKEEPERS_DICT: &str = "gatekeepers";
+const GATEKEEPERS_BY_INDEX_DICT: &str = "gatekeepers_by_index";
+const GATEKEEPER_INDICES_DICT: &str = "gatekeeper_indices";
+const GATEKEEPER_COUNT: &str = "gatekeeper_count";
+
 pub trait GateKeeperControl<Storage: ContractStorage>: ContractContext<Storage> {
     fn init(&mut self) {
         GateKeepers::init();
+        IndexedSet::<Key>::init(
+            GATEKEEPERS_BY_INDEX_DICT,
+            GATEKEEPER_INDICES_DICT,
+            GATEKEEPER_COUNT,
+        );
     }
 
     fn revoke_gatekeeper(&mut self, address: Key) {
         GateKeepers::instance().revoke_gatekeeper(&address);
+        gatekeeper_index().remove(&address);
     }
 
     fn add_gatekeeper(&mut self, address: Key) {
         GateKeepers::instance().add_gatekeeper(&address);
+        gatekeeper_index().insert(address);
     }
 
-    fn is_gatekeeper(&self) -> bool {
-        let caller = self.get_caller();
-        GateKeepers::instance().is_gatekeeper(&caller)
+    fn gatekeeper_count(&self) -> u64 {
+        gatekeeper_index().count()
     }
 
-    fn assert_caller_is_gatekeeper(&self) {
-        if !self.is_gatekeeper() {
-            runtime::revert(ApiError::User(20));
-        }
+    fn gatekeeper_at(&self, index: u64) -> Option<Key> {
+        gatekeeper_index().at(index)
     }
+
+    fn is_gatekeeper_key(&self, key: Key) -> bool {
+        GateKeepers::instance().is_gatekeeper(&key)
+    }
+}
+
+fn gatekeeper_index() -> IndexedSet<Key> {
+    IndexedSet::instance(
+        GATEKEEPERS_BY_INDEX_DICT,
+        GATEKEEPER_INDICES_DICT,
+        GATEKEEPER_COUNT,
+    )
 }
 
 struct GateKeepers {
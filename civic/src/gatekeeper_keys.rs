@@ -0,0 +1,121 @@
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::Key;
+use cep47::contract_utils::{ContractContext, ContractStorage, Dict};
+
+use crate::error::Error;
+
+const IDENTITIES_DICT: &str = "gatekeeper_identities";
+const CURRENT_KEYS_DICT: &str = "gatekeeper_keys";
+const PENDING_ROTATIONS_DICT: &str = "pending_key_rotations";
+const RESIGNED_DICT: &str = "resigned_gatekeepers";
+
+/// Stable identity of a gatekeeper across key rotations. A gatekeeper's identity is
+/// the first key it was granted with; per-gatekeeper records are kept under it.
+pub trait GatekeeperKeys<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(&mut self) {
+        KeyMap::init(IDENTITIES_DICT);
+        KeyMap::init(CURRENT_KEYS_DICT);
+        KeyMap::init(PENDING_ROTATIONS_DICT);
+        storage::new_dictionary(RESIGNED_DICT).unwrap_or_revert();
+    }
+
+    /// Identity a key was assigned, whether or not it can still sign for it.
+    fn key_identity(&self, key: Key) -> Key {
+        KeyMap::instance(IDENTITIES_DICT).get(&key).unwrap_or(key)
+    }
+
+    /// Identity the key currently signs for, `None` once the key was rotated
+    /// away or its gatekeeper resigned.
+    fn gatekeeper_identity(&self, key: Key) -> Option<Key> {
+        let identity = self.key_identity(key);
+        let current_key = KeyMap::instance(CURRENT_KEYS_DICT)
+            .get(&identity)
+            .unwrap_or(identity);
+        let resigned = Dict::instance(RESIGNED_DICT)
+            .get_by_key::<()>(&identity)
+            .is_some();
+        if current_key == key && !resigned {
+            Some(identity)
+        } else {
+            None
+        }
+    }
+
+    /// Records a granted gatekeeper key as an identity of its own, unless it
+    /// already belongs to one, so no other gatekeeper can rotate onto it.
+    fn record_identity(&mut self, key: Key) {
+        let identities = KeyMap::instance(IDENTITIES_DICT);
+        if identities.get(&key).is_none() {
+            identities.set(&key, Some(key));
+        }
+    }
+
+    fn pending_key_rotation(&self, new_key: Key) -> Option<Key> {
+        KeyMap::instance(PENDING_ROTATIONS_DICT).get(&new_key)
+    }
+
+    /// Proposes moving the identity of `current_key` to a key that never belonged
+    /// to a gatekeeper and that no other rotation is waiting for.
+    fn propose_key_rotation(&mut self, current_key: Key, new_key: Key) {
+        let known = KeyMap::instance(IDENTITIES_DICT).get(&new_key).is_some()
+            || KeyMap::instance(CURRENT_KEYS_DICT).get(&new_key).is_some()
+            || self.pending_key_rotation(new_key).is_some();
+        if known || new_key == current_key {
+            runtime::revert(Error::KeyInUse);
+        }
+        KeyMap::instance(PENDING_ROTATIONS_DICT).set(&new_key, Some(current_key));
+    }
+
+    /// Moves the identity of `old_key` to `new_key` when `old_key` proposed the rotation.
+    /// The retired key keeps pointing at the identity.
+    fn confirm_key_rotation(&mut self, old_key: Key, new_key: Key) {
+        let pending_rotations = KeyMap::instance(PENDING_ROTATIONS_DICT);
+        if pending_rotations.get(&new_key) != Some(old_key) {
+            runtime::revert(Error::UnknownKeyRotation);
+        }
+        let identity = self
+            .gatekeeper_identity(old_key)
+            .unwrap_or_revert_with(Error::PermissionDenied);
+        KeyMap::instance(IDENTITIES_DICT).set(&new_key, Some(identity));
+        KeyMap::instance(CURRENT_KEYS_DICT).set(&identity, Some(new_key));
+        pending_rotations.set(&new_key, None);
+    }
+
+    fn resign(&mut self, identity: Key) {
+        Dict::instance(RESIGNED_DICT).set_by_key(&identity, ());
+    }
+
+    fn reinstate(&mut self, identity: Key) {
+        Dict::instance(RESIGNED_DICT).remove_by_key::<()>(&identity);
+    }
+}
+
+struct KeyMap {
+    dict: Dict,
+}
+
+impl KeyMap {
+    pub fn instance(name: &str) -> KeyMap {
+        KeyMap {
+            dict: Dict::instance(name),
+        }
+    }
+
+    pub fn init(name: &str) {
+        storage::new_dictionary(name).unwrap_or_revert();
+    }
+
+    pub fn get(&self, key: &Key) -> Option<Key> {
+        self.dict.get_by_key(key)
+    }
+
+    pub fn set(&self, key: &Key, value: Option<Key>) {
+        match value {
+            Some(value) => self.dict.set_by_key(key, value),
+            None => self.dict.remove_by_key::<Key>(key),
+        }
+    }
+}
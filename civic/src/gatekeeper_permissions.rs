@@ -0,0 +1,64 @@
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::Key;
+use cep47::contract_utils::{ContractContext, ContractStorage, Dict};
+
+/// Bitmask of the token actions a gatekeeper may take.
+pub type Permissions = u8;
+
+pub const MINT: Permissions = 1;
+pub const REFRESH: Permissions = 1 << 1;
+pub const FREEZE: Permissions = 1 << 2;
+pub const REVOKE: Permissions = 1 << 3;
+pub const EDIT_METADATA: Permissions = 1 << 4;
+pub const ALL_PERMISSIONS: Permissions = MINT | REFRESH | FREEZE | REVOKE | EDIT_METADATA;
+
+const PERMISSIONS_DICT: &str = "gatekeeper_permissions";
+
+/// Per-gatekeeper restrictions. Gatekeepers without an entry hold every permission.
+pub trait GatekeeperPermissions<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(&mut self) {
+        PermissionMasks::init();
+    }
+
+    fn gatekeeper_permissions(&self, gatekeeper: Key) -> Permissions {
+        PermissionMasks::instance()
+            .get(&gatekeeper)
+            .unwrap_or(ALL_PERMISSIONS)
+    }
+
+    fn set_gatekeeper_permissions(&mut self, gatekeeper: Key, permissions: Permissions) {
+        PermissionMasks::instance().set(&gatekeeper, permissions & ALL_PERMISSIONS);
+    }
+
+    fn has_permissions(&self, gatekeeper: Key, permissions: Permissions) -> bool {
+        self.gatekeeper_permissions(gatekeeper) & permissions == permissions
+    }
+}
+
+struct PermissionMasks {
+    dict: Dict,
+}
+
+impl PermissionMasks {
+    pub fn instance() -> PermissionMasks {
+        PermissionMasks {
+            dict: Dict::instance(PERMISSIONS_DICT),
+        }
+    }
+
+    pub fn init() {
+        storage::new_dictionary(PERMISSIONS_DICT).unwrap_or_revert();
+    }
+
+    pub fn get(&self, gatekeeper: &Key) -> Option<Permissions> {
+        self.dict.get_by_key(gatekeeper)
+    }
+
+    pub fn set(&self, gatekeeper: &Key, permissions: Permissions) {
+        if permissions == ALL_PERMISSIONS {
+            self.dict.remove_by_key::<Permissions>(gatekeeper);
+        } else {
+            self.dict.set_by_key(gatekeeper, permissions);
+        }
+    }
+}
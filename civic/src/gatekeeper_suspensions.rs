@@ -0,0 +1,61 @@
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::Key;
+use cep47::contract_utils::{ContractContext, ContractStorage, Dict};
+
+const SUSPENSIONS_DICT: &str = "gatekeeper_suspensions";
+
+/// Temporary suspensions of gatekeeper identities. A suspended gatekeeper keeps
+/// its roles, records and tokens but cannot mint or edit tokens.
+pub trait GatekeeperSuspensions<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(&mut self) {
+        Suspensions::init();
+    }
+
+    /// Block time until which the gatekeeper is suspended.
+    fn suspended_until(&self, gatekeeper: Key) -> Option<u64> {
+        Suspensions::instance().get(&gatekeeper)
+    }
+
+    fn is_suspended(&self, gatekeeper: Key, now: u64) -> bool {
+        match self.suspended_until(gatekeeper) {
+            Some(until) => now < until,
+            None => false,
+        }
+    }
+
+    fn suspend(&mut self, gatekeeper: Key, until: u64) {
+        Suspensions::instance().set(&gatekeeper, until);
+    }
+
+    fn lift_suspension(&mut self, gatekeeper: Key) {
+        Suspensions::instance().remove(&gatekeeper);
+    }
+}
+
+struct Suspensions {
+    dict: Dict,
+}
+
+impl Suspensions {
+    pub fn instance() -> Suspensions {
+        Suspensions {
+            dict: Dict::instance(SUSPENSIONS_DICT),
+        }
+    }
+
+    pub fn init() {
+        storage::new_dictionary(SUSPENSIONS_DICT).unwrap_or_revert();
+    }
+
+    pub fn get(&self, gatekeeper: &Key) -> Option<u64> {
+        self.dict.get_by_key(gatekeeper)
+    }
+
+    pub fn set(&self, gatekeeper: &Key, until: u64) {
+        self.dict.set_by_key(gatekeeper, until);
+    }
+
+    pub fn remove(&self, gatekeeper: &Key) {
+        self.dict.remove_by_key::<u64>(gatekeeper);
+    }
+}
@@ -0,0 +1,140 @@
+use alloc::vec::Vec;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::Key;
+use cep47::{
+    contract_utils::{ContractContext, ContractStorage, Dict},
+    TokenId,
+};
+
+use crate::{
+    error::Error,
+    network_control::{network_and_value_to_str, NetworkId},
+};
+
+/// Salted hash of a person's identity, computed off chain by the gatekeeper.
+pub type Commitment = [u8; 32];
+
+const TOKEN_IDENTITIES_DICT: &str = "token_identities";
+const IDENTITY_ACCOUNTS_DICT: &str = "identity_accounts";
+
+pub trait IdentityRegistry<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(&mut self) {
+        TokenIdentities::init();
+        IdentityAccounts::init();
+    }
+
+    fn token_identity(&self, token_id: &TokenId) -> Option<Commitment> {
+        TokenIdentities::instance().get(token_id)
+    }
+
+    fn identity_accounts(&self, network: NetworkId, commitment: &Commitment) -> Vec<Key> {
+        IdentityAccounts::instance().get(network, commitment)
+    }
+
+    /// Links a freshly minted token to an identity. Reverts when the identity
+    /// already holds a live token in the network and `allow_multiple` is not set.
+    fn register_identity(
+        &mut self,
+        network: NetworkId,
+        token_id: &TokenId,
+        commitment: Commitment,
+        account: Key,
+        allow_multiple: bool,
+    ) {
+        let identity_accounts = IdentityAccounts::instance();
+        let mut accounts = identity_accounts.get(network, &commitment);
+        if !accounts.is_empty() && !allow_multiple {
+            runtime::revert(Error::IdentityAlreadyRegistered);
+        }
+        accounts.push(account);
+        identity_accounts.set(network, &commitment, accounts);
+        TokenIdentities::instance().set(token_id, commitment);
+    }
+
+    fn release_identity(&mut self, network: NetworkId, token_id: &TokenId, account: Key) {
+        let token_identities = TokenIdentities::instance();
+        if let Some(commitment) = token_identities.get(token_id) {
+            let identity_accounts = IdentityAccounts::instance();
+            let mut accounts = identity_accounts.get(network, &commitment);
+            if let Some(position) = accounts.iter().position(|holder| *holder == account) {
+                accounts.remove(position);
+            }
+            identity_accounts.set(network, &commitment, accounts);
+            token_identities.remove(token_id);
+        }
+    }
+
+    fn move_identity(&mut self, network: NetworkId, token_id: &TokenId, from: Key, to: Key) {
+        if let Some(commitment) = TokenIdentities::instance().get(token_id) {
+            let identity_accounts = IdentityAccounts::instance();
+            let mut accounts = identity_accounts.get(network, &commitment);
+            if let Some(holder) = accounts.iter_mut().find(|holder| **holder == from) {
+                *holder = to;
+            }
+            identity_accounts.set(network, &commitment, accounts);
+        }
+    }
+}
+
+struct TokenIdentities {
+    dict: Dict,
+}
+
+impl TokenIdentities {
+    pub fn instance() -> TokenIdentities {
+        TokenIdentities {
+            dict: Dict::instance(TOKEN_IDENTITIES_DICT),
+        }
+    }
+
+    pub fn init() {
+        storage::new_dictionary(TOKEN_IDENTITIES_DICT).unwrap_or_revert();
+    }
+
+    pub fn get(&self, token_id: &TokenId) -> Option<Commitment> {
+        self.dict.get(token_id)
+    }
+
+    pub fn set(&self, token_id: &TokenId, commitment: Commitment) {
+        self.dict.set(token_id, commitment);
+    }
+
+    pub fn remove(&self, token_id: &TokenId) {
+        self.dict.remove::<Commitment>(token_id);
+    }
+}
+
+struct IdentityAccounts {
+    dict: Dict,
+}
+
+impl IdentityAccounts {
+    pub fn instance() -> IdentityAccounts {
+        IdentityAccounts {
+            dict: Dict::instance(IDENTITY_ACCOUNTS_DICT),
+        }
+    }
+
+    pub fn init() {
+        storage::new_dictionary(IDENTITY_ACCOUNTS_DICT).unwrap_or_revert();
+    }
+
+    pub fn get(&self, network: NetworkId, commitment: &Commitment) -> Vec<Key> {
+        self.dict
+            .get(&network_and_value_to_str(network, commitment))
+            .unwrap_or_default()
+    }
+
+    pub fn set(&self, network: NetworkId, commitment: &Commitment, accounts: Vec<Key>) {
+        let key = network_and_value_to_str(network, commitment);
+        if accounts.is_empty() {
+            self.dict.remove::<Vec<Key>>(&key);
+        } else {
+            self.dict.set(&key, accounts);
+        }
+    }
+}
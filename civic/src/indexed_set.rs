@@ -0,0 +1,97 @@
+use alloc::string::{String, ToString};
+use core::marker::PhantomData;
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{
+    bytesrepr::{FromBytes, ToBytes},
+    CLTyped, Key,
+};
+use cep47::{
+    contract_utils::{get_key, key_to_str, set_key, Dict},
+    TokenId,
+};
+
+/// Value that can be listed in an `IndexedSet`, under a dictionary item key of its own.
+pub trait IndexItem: CLTyped + ToBytes + FromBytes {
+    fn item_key(&self) -> String;
+}
+
+impl IndexItem for Key {
+    fn item_key(&self) -> String {
+        key_to_str(self)
+    }
+}
+
+impl IndexItem for TokenId {
+    fn item_key(&self) -> String {
+        self.clone()
+    }
+}
+
+/// Dense list of items that can be enumerated by index. Removing an item moves
+/// the last one into its slot.
+pub struct IndexedSet<T: IndexItem> {
+    by_index: Dict,
+    indices: Dict,
+    count_key: &'static str,
+    item: PhantomData<T>,
+}
+
+impl<T: IndexItem> IndexedSet<T> {
+    pub fn instance(
+        by_index_dict: &str,
+        indices_dict: &str,
+        count_key: &'static str,
+    ) -> IndexedSet<T> {
+        IndexedSet {
+            by_index: Dict::instance(by_index_dict),
+            indices: Dict::instance(indices_dict),
+            count_key,
+            item: PhantomData,
+        }
+    }
+
+    pub fn init(by_index_dict: &str, indices_dict: &str, count_key: &str) {
+        storage::new_dictionary(by_index_dict).unwrap_or_revert();
+        storage::new_dictionary(indices_dict).unwrap_or_revert();
+        set_key(count_key, 0u64);
+    }
+
+    pub fn count(&self) -> u64 {
+        get_key(self.count_key).unwrap_or_default()
+    }
+
+    pub fn at(&self, index: u64) -> Option<T> {
+        self.by_index.get(&index.to_string())
+    }
+
+    pub fn contains(&self, item: &T) -> bool {
+        self.indices.get::<u64>(&item.item_key()).is_some()
+    }
+
+    pub fn insert(&self, item: T) {
+        if self.contains(&item) {
+            return;
+        }
+        let item_key = item.item_key();
+        let count = self.count();
+        self.by_index.set(&count.to_string(), item);
+        self.indices.set(&item_key, count);
+        set_key(self.count_key, count + 1);
+    }
+
+    pub fn remove(&self, item: &T) {
+        let item_key = item.item_key();
+        if let Some(index) = self.indices.get::<u64>(&item_key) {
+            let last = self.count() - 1;
+            if index != last {
+                let moved: T = self.by_index.get(&last.to_string()).unwrap_or_revert();
+                self.indices.set(&moved.item_key(), index);
+                self.by_index.set(&index.to_string(), moved);
+            }
+            self.by_index.remove::<T>(&last.to_string());
+            self.indices.remove::<u64>(&item_key);
+            set_key(self.count_key, last);
+        }
+    }
+}
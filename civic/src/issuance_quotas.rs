@@ -0,0 +1,205 @@
+use alloc::vec::Vec;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::Key;
+use cep47::{
+    contract_utils::{ContractContext, ContractStorage, Dict},
+    TokenId,
+};
+
+use crate::error::Error;
+
+/// Issuance limits of a gatekeeper: maximum live tokens, maximum mints per
+/// window and the window length in milliseconds of block time. A zero disables a limit.
+pub type Quota = (u64, u32, u64);
+
+/// Gatekeeper that minted a token and the block time of the mint.
+pub type Issuance = (Key, u64);
+
+const QUOTAS_DICT: &str = "gatekeeper_quotas";
+const LIVE_TOKENS_DICT: &str = "gatekeeper_live_tokens";
+const RECENT_MINTS_DICT: &str = "gatekeeper_recent_mints";
+const TOKEN_ISSUANCES_DICT: &str = "token_issuances";
+
+pub trait IssuanceQuotas<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(&mut self) {
+        Quotas::init();
+        LiveTokens::init();
+        RecentMints::init();
+        TokenIssuances::init();
+    }
+
+    fn gatekeeper_quota(&self, gatekeeper: Key) -> Option<Quota> {
+        Quotas::instance().get(&gatekeeper)
+    }
+
+    fn set_gatekeeper_quota(&mut self, gatekeeper: Key, quota: Option<Quota>) {
+        Quotas::instance().set(&gatekeeper, quota);
+    }
+
+    fn gatekeeper_live_tokens(&self, gatekeeper: Key) -> u64 {
+        LiveTokens::instance().get(&gatekeeper)
+    }
+
+    fn gatekeeper_mints_in_window(&self, gatekeeper: Key, now: u64) -> u32 {
+        let window = self
+            .gatekeeper_quota(gatekeeper)
+            .map_or(0, |(_, _, window)| window);
+        RecentMints::instance()
+            .get(&gatekeeper)
+            .iter()
+            .filter(|minted_at| now.saturating_sub(**minted_at) < window)
+            .count() as u32
+    }
+
+    fn token_issuance(&self, token_id: &TokenId) -> Option<Issuance> {
+        TokenIssuances::instance().get(token_id)
+    }
+
+    /// Counts a mint against the gatekeeper's quota, reverting once a limit is reached.
+    fn record_issuance(&mut self, token_id: &TokenId, gatekeeper: Key, now: u64) {
+        let (max_live_tokens, max_mints, window) =
+            self.gatekeeper_quota(gatekeeper).unwrap_or_default();
+
+        let live_tokens = LiveTokens::instance();
+        let live = live_tokens.get(&gatekeeper);
+        if max_live_tokens > 0 && live >= max_live_tokens {
+            runtime::revert(Error::QuotaExceeded);
+        }
+        live_tokens.set(&gatekeeper, live + 1);
+
+        // Mint times are only kept while a rate limit is configured, pruned to the window.
+        let recent_mints = RecentMints::instance();
+        let mut minted = recent_mints.get(&gatekeeper);
+        minted.retain(|minted_at| now.saturating_sub(*minted_at) < window);
+        if max_mints > 0 && window > 0 {
+            if minted.len() >= max_mints as usize {
+                runtime::revert(Error::QuotaExceeded);
+            }
+            minted.push(now);
+        }
+        recent_mints.set(&gatekeeper, minted);
+
+        TokenIssuances::instance().set(token_id, (gatekeeper, now));
+    }
+
+    fn release_issuance(&mut self, token_id: &TokenId) {
+        let token_issuances = TokenIssuances::instance();
+        if let Some((gatekeeper, _)) = token_issuances.get(token_id) {
+            let live_tokens = LiveTokens::instance();
+            let live = live_tokens.get(&gatekeeper);
+            live_tokens.set(&gatekeeper, live.saturating_sub(1));
+            token_issuances.remove(token_id);
+        }
+    }
+}
+
+struct Quotas {
+    dict: Dict,
+}
+
+impl Quotas {
+    pub fn instance() -> Quotas {
+        Quotas {
+            dict: Dict::instance(QUOTAS_DICT),
+        }
+    }
+
+    pub fn init() {
+        storage::new_dictionary(QUOTAS_DICT).unwrap_or_revert();
+    }
+
+    pub fn get(&self, gatekeeper: &Key) -> Option<Quota> {
+        self.dict.get_by_key(gatekeeper)
+    }
+
+    pub fn set(&self, gatekeeper: &Key, quota: Option<Quota>) {
+        match quota {
+            Some(quota) => self.dict.set_by_key(gatekeeper, quota),
+            None => self.dict.remove_by_key::<Quota>(gatekeeper),
+        }
+    }
+}
+
+struct LiveTokens {
+    dict: Dict,
+}
+
+impl LiveTokens {
+    pub fn instance() -> LiveTokens {
+        LiveTokens {
+            dict: Dict::instance(LIVE_TOKENS_DICT),
+        }
+    }
+
+    pub fn init() {
+        storage::new_dictionary(LIVE_TOKENS_DICT).unwrap_or_revert();
+    }
+
+    pub fn get(&self, gatekeeper: &Key) -> u64 {
+        self.dict.get_by_key(gatekeeper).unwrap_or_default()
+    }
+
+    pub fn set(&self, gatekeeper: &Key, live: u64) {
+        self.dict.set_by_key(gatekeeper, live);
+    }
+}
+
+struct RecentMints {
+    dict: Dict,
+}
+
+impl RecentMints {
+    pub fn instance() -> RecentMints {
+        RecentMints {
+            dict: Dict::instance(RECENT_MINTS_DICT),
+        }
+    }
+
+    pub fn init() {
+        storage::new_dictionary(RECENT_MINTS_DICT).unwrap_or_revert();
+    }
+
+    pub fn get(&self, gatekeeper: &Key) -> Vec<u64> {
+        self.dict.get_by_key(gatekeeper).unwrap_or_default()
+    }
+
+    pub fn set(&self, gatekeeper: &Key, minted: Vec<u64>) {
+        if minted.is_empty() {
+            self.dict.remove_by_key::<Vec<u64>>(gatekeeper);
+        } else {
+            self.dict.set_by_key(gatekeeper, minted);
+        }
+    }
+}
+
+struct TokenIssuances {
+    dict: Dict,
+}
+
+impl TokenIssuances {
+    pub fn instance() -> TokenIssuances {
+        TokenIssuances {
+            dict: Dict::instance(TOKEN_ISSUANCES_DICT),
+        }
+    }
+
+    pub fn init() {
+        storage::new_dictionary(TOKEN_ISSUANCES_DICT).unwrap_or_revert();
+    }
+
+    pub fn get(&self, token_id: &TokenId) -> Option<Issuance> {
+        self.dict.get(token_id)
+    }
+
+    pub fn set(&self, token_id: &TokenId, issuance: Issuance) {
+        self.dict.set(token_id, issuance);
+    }
+
+    pub fn remove(&self, token_id: &TokenId) {
+        self.dict.remove::<Issuance>(token_id);
+    }
+}
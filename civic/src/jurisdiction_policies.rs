@@ -0,0 +1,126 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::Key;
+use cep47::{
+    contract_utils::{get_key, set_key, ContractContext, ContractStorage, Dict},
+    TokenId,
+};
+
+use crate::error::Error;
+
+pub type PolicyId = u64;
+
+/// Jurisdiction policy of a consumer: owner, whether `jurisdictions` is an
+/// allow list (`true`) or a deny list (`false`), and the ISO 3166-1 alpha-2 codes.
+pub type Policy = (Key, bool, Vec<String>);
+
+const TOKEN_JURISDICTIONS_DICT: &str = "token_jurisdictions";
+const POLICIES_DICT: &str = "jurisdiction_policies";
+const POLICY_COUNT: &str = "jurisdiction_policy_count";
+
+pub trait JurisdictionPolicies<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(&mut self) {
+        TokenJurisdictions::init();
+        Policies::init();
+        set_key(POLICY_COUNT, PolicyId::default());
+    }
+
+    fn token_jurisdiction(&self, token_id: &TokenId) -> Option<String> {
+        TokenJurisdictions::instance().get(token_id)
+    }
+
+    fn set_token_jurisdiction(&mut self, token_id: &TokenId, jurisdiction: Option<String>) {
+        TokenJurisdictions::instance().set(token_id, jurisdiction);
+    }
+
+    fn policy(&self, policy_id: PolicyId) -> Option<Policy> {
+        Policies::instance().get(policy_id)
+    }
+
+    fn register_policy(&mut self, owner: Key, allow: bool, jurisdictions: Vec<String>) -> PolicyId {
+        let policy_id: PolicyId = get_key(POLICY_COUNT).unwrap_or_default();
+        Policies::instance().set(policy_id, (owner, allow, jurisdictions));
+        set_key(POLICY_COUNT, policy_id + 1);
+        policy_id
+    }
+
+    fn update_policy(&mut self, policy_id: PolicyId, allow: bool, jurisdictions: Vec<String>) {
+        let policies = Policies::instance();
+        let (owner, _, _) = policies
+            .get(policy_id)
+            .unwrap_or_revert_with(Error::UnknownPolicy);
+        if owner != self.get_caller() {
+            runtime::revert(Error::PermissionDenied);
+        }
+        policies.set(policy_id, (owner, allow, jurisdictions));
+    }
+
+    /// Tokens without a jurisdiction claim never satisfy a policy.
+    fn is_jurisdiction_allowed(&self, policy_id: PolicyId, token_id: &TokenId) -> bool {
+        let (_, allow, jurisdictions) = self
+            .policy(policy_id)
+            .unwrap_or_revert_with(Error::UnknownPolicy);
+        match self.token_jurisdiction(token_id) {
+            Some(jurisdiction) => jurisdictions.contains(&jurisdiction) == allow,
+            None => false,
+        }
+    }
+}
+
+struct TokenJurisdictions {
+    dict: Dict,
+}
+
+impl TokenJurisdictions {
+    pub fn instance() -> TokenJurisdictions {
+        TokenJurisdictions {
+            dict: Dict::instance(TOKEN_JURISDICTIONS_DICT),
+        }
+    }
+
+    pub fn init() {
+        storage::new_dictionary(TOKEN_JURISDICTIONS_DICT).unwrap_or_revert();
+    }
+
+    pub fn get(&self, token_id: &TokenId) -> Option<String> {
+        self.dict.get(token_id)
+    }
+
+    pub fn set(&self, token_id: &TokenId, jurisdiction: Option<String>) {
+        match jurisdiction {
+            Some(jurisdiction) => self.dict.set(token_id, jurisdiction),
+            None => self.dict.remove::<String>(token_id),
+        }
+    }
+}
+
+struct Policies {
+    dict: Dict,
+}
+
+impl Policies {
+    pub fn instance() -> Policies {
+        Policies {
+            dict: Dict::instance(POLICIES_DICT),
+        }
+    }
+
+    pub fn init() {
+        storage::new_dictionary(POLICIES_DICT).unwrap_or_revert();
+    }
+
+    pub fn get(&self, policy_id: PolicyId) -> Option<Policy> {
+        self.dict.get(&policy_id.to_string())
+    }
+
+    pub fn set(&self, policy_id: PolicyId, policy: Policy) {
+        self.dict.set(&policy_id.to_string(), policy);
+    }
+}
@@ -0,0 +1,86 @@
+use alloc::vec::Vec;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::Key;
+use cep47::contract_utils::{ContractContext, ContractStorage, Dict};
+
+use crate::error::Error;
+
+const PENDING_LINKS_DICT: &str = "pending_key_links";
+const LINKED_HOLDERS_DICT: &str = "linked_key_holders";
+const HOLDER_LINKS_DICT: &str = "holder_linked_keys";
+
+/// Accounts and contract packages linked to a credential holder. A link is
+/// proposed by the holder and confirmed by the linked key.
+pub trait LinkedKeys<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(&mut self) {
+        storage::new_dictionary(PENDING_LINKS_DICT).unwrap_or_revert();
+        storage::new_dictionary(LINKED_HOLDERS_DICT).unwrap_or_revert();
+        storage::new_dictionary(HOLDER_LINKS_DICT).unwrap_or_revert();
+    }
+
+    fn linked_holder(&self, key: Key) -> Option<Key> {
+        Dict::instance(LINKED_HOLDERS_DICT).get_by_key(&key)
+    }
+
+    fn pending_link(&self, key: Key) -> Option<Key> {
+        Dict::instance(PENDING_LINKS_DICT).get_by_key(&key)
+    }
+
+    fn linked_keys(&self, holder: Key) -> Vec<Key> {
+        Dict::instance(HOLDER_LINKS_DICT)
+            .get_by_key(&holder)
+            .unwrap_or_default()
+    }
+
+    /// Proposes linking `key` to the holder, unless another holder's proposal to
+    /// it is still pending.
+    fn propose_link(&mut self, holder: Key, key: Key) {
+        if key == holder
+            || self.linked_holder(key).is_some()
+            || self.linked_holder(holder).is_some()
+            || matches!(self.pending_link(key), Some(pending) if pending != holder)
+        {
+            runtime::revert(Error::KeyInUse);
+        }
+        Dict::instance(PENDING_LINKS_DICT).set_by_key(&key, holder);
+    }
+
+    /// Completes the link `holder` proposed to `key`.
+    fn confirm_link(&mut self, key: Key, holder: Key) {
+        if self.pending_link(key) != Some(holder) {
+            runtime::revert(Error::UnknownLink);
+        }
+        if self.linked_holder(key).is_some() {
+            runtime::revert(Error::KeyInUse);
+        }
+        Dict::instance(PENDING_LINKS_DICT).remove_by_key::<Key>(&key);
+        Dict::instance(LINKED_HOLDERS_DICT).set_by_key(&key, holder);
+        let mut keys = self.linked_keys(holder);
+        keys.push(key);
+        Dict::instance(HOLDER_LINKS_DICT).set_by_key(&holder, keys);
+    }
+
+    /// Drops a pending or confirmed link of the holder to `key`.
+    fn unlink(&mut self, holder: Key, key: Key) {
+        if self.pending_link(key) == Some(holder) {
+            Dict::instance(PENDING_LINKS_DICT).remove_by_key::<Key>(&key);
+            return;
+        }
+        if self.linked_holder(key) != Some(holder) {
+            runtime::revert(Error::UnknownLink);
+        }
+        Dict::instance(LINKED_HOLDERS_DICT).remove_by_key::<Key>(&key);
+        let mut keys = self.linked_keys(holder);
+        keys.retain(|linked_key| *linked_key != key);
+        let holder_links = Dict::instance(HOLDER_LINKS_DICT);
+        if keys.is_empty() {
+            holder_links.remove_by_key::<Vec<Key>>(&holder);
+        } else {
+            holder_links.set_by_key(&holder, keys);
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//! Rules for which token metadata keys a gatekeeper may write.
+//!
+//! `status` and `expiry` are reserved and cannot name a typed claim. `status` only
+//! changes through the token action entry points, `expiry` through the refresh
+//! permission. Keys prefixed `gk:<gatekeeper>:` belong to that gatekeeper. Every
+//! other key is fixed when the token is minted.
+
+use alloc::{format, string::String};
+
+use casper_types::Key;
+use cep47::contract_utils::key_to_str;
+
+use crate::{
+    gatekeeper_permissions::{Permissions, EDIT_METADATA, REFRESH},
+    EXPIRY_KEY, STATUS_KEY,
+};
+
+pub const NAMESPACE_PREFIX: &str = "gk:";
+
+/// Key prefix owned by a gatekeeper identity.
+pub fn gatekeeper_namespace(gatekeeper: &Key) -> String {
+    format!("{}{}:", NAMESPACE_PREFIX, key_to_str(gatekeeper))
+}
+
+/// Whether the key is written by the contract itself rather than set as a claim.
+pub fn is_reserved(key: &str) -> bool {
+    key == STATUS_KEY || key == EXPIRY_KEY
+}
+
+/// Permissions the gatekeeper needs to change the key of a minted token, `None`
+/// when it may not change it.
+pub fn edit_permissions(key: &str, gatekeeper: &Key) -> Option<Permissions> {
+    match key {
+        EXPIRY_KEY => Some(REFRESH),
+        _ if key.starts_with(&gatekeeper_namespace(gatekeeper)) => Some(EDIT_METADATA),
+        _ => None,
+    }
+}
+
+/// Whether the gatekeeper may set the key when minting, i.e. the key is not in
+/// another gatekeeper's namespace.
+pub fn may_set_at_mint(key: &str, gatekeeper: &Key) -> bool {
+    !key.starts_with(NAMESPACE_PREFIX) || key.starts_with(&gatekeeper_namespace(gatekeeper))
+}
@@ -0,0 +1,182 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::Key;
+use cep47::contract_utils::{get_key, set_key, ContractContext, ContractStorage, Dict};
+
+use crate::error::Error;
+
+pub type ProposalId = u64;
+
+/// Entry point a proposal executes, its serialized `RuntimeArgs` and its expiry in block time.
+pub type Proposal = (String, Vec<u8>, u64);
+
+/// Entry points that need `approval_threshold` admin approvals once the threshold exceeds one.
+pub const SENSITIVE_ENTRY_POINTS: [&str; 9] = [
+    "grant_admin",
+    "revoke_admin",
+    "emergency_revoke_admin",
+    "grant_gatekeeper",
+    "transfer_from",
+    "set_multisig_config",
+    "set_role_change_delay",
+    "trust_gateway",
+    "sunset",
+];
+
+pub const DEFAULT_PROPOSAL_LIFETIME: u64 = 7 * 24 * 60 * 60 * 1000;
+
+const PROPOSALS_DICT: &str = "proposals";
+const APPROVALS_DICT: &str = "proposal_approvals";
+const PROPOSAL_COUNT: &str = "proposal_count";
+const APPROVAL_THRESHOLD: &str = "approval_threshold";
+const PROPOSAL_LIFETIME: &str = "proposal_lifetime";
+
+pub trait Multisig<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(&mut self) {
+        Proposals::init();
+        Approvals::init();
+        set_key(PROPOSAL_COUNT, ProposalId::default());
+        set_key(APPROVAL_THRESHOLD, 1u32);
+        set_key(PROPOSAL_LIFETIME, DEFAULT_PROPOSAL_LIFETIME);
+    }
+
+    fn approval_threshold(&self) -> u32 {
+        get_key(APPROVAL_THRESHOLD).unwrap_or(1)
+    }
+
+    fn proposal_lifetime(&self) -> u64 {
+        get_key(PROPOSAL_LIFETIME).unwrap_or(DEFAULT_PROPOSAL_LIFETIME)
+    }
+
+    fn set_multisig_config(&mut self, threshold: u32, proposal_lifetime: u64) {
+        set_key(APPROVAL_THRESHOLD, threshold);
+        set_key(PROPOSAL_LIFETIME, proposal_lifetime);
+    }
+
+    fn is_sensitive(&self, entry_point: &str) -> bool {
+        SENSITIVE_ENTRY_POINTS.contains(&entry_point)
+    }
+
+    fn assert_direct_call_allowed(&self, entry_point: &str) {
+        if self.is_sensitive(entry_point) && self.approval_threshold() > 1 {
+            runtime::revert(Error::ApprovalRequired);
+        }
+    }
+
+    fn proposal(&self, proposal_id: ProposalId) -> Option<Proposal> {
+        Proposals::instance().get(proposal_id)
+    }
+
+    fn proposal_approvals(&self, proposal_id: ProposalId) -> Vec<Key> {
+        Approvals::instance().get(proposal_id)
+    }
+
+    /// Stores a proposal for a sensitive entry point, approved by its proposer.
+    fn propose(
+        &mut self,
+        entry_point: String,
+        args: Vec<u8>,
+        proposer: Key,
+        now: u64,
+    ) -> ProposalId {
+        if !self.is_sensitive(&entry_point) {
+            runtime::revert(Error::UnknownAction);
+        }
+        let proposal_id: ProposalId = get_key(PROPOSAL_COUNT).unwrap_or_default();
+        let expires_at = now.saturating_add(self.proposal_lifetime());
+        Proposals::instance().set(proposal_id, (entry_point, args, expires_at));
+        Approvals::instance().set(proposal_id, vec![proposer]);
+        set_key(PROPOSAL_COUNT, proposal_id + 1);
+        proposal_id
+    }
+
+    fn approve(&mut self, proposal_id: ProposalId, approver: Key, now: u64) {
+        let (_, _, expires_at) = self
+            .proposal(proposal_id)
+            .unwrap_or_revert_with(Error::UnknownProposal);
+        if now >= expires_at {
+            runtime::revert(Error::ProposalExpired);
+        }
+        let approvals = Approvals::instance();
+        let mut approvers = approvals.get(proposal_id);
+        if approvers.contains(&approver) {
+            runtime::revert(Error::AlreadyApproved);
+        }
+        approvers.push(approver);
+        approvals.set(proposal_id, approvers);
+    }
+
+    /// Removes a proposal so that it can be executed exactly once.
+    fn take_proposal(&mut self, proposal_id: ProposalId) -> Proposal {
+        let proposals = Proposals::instance();
+        let proposal = proposals
+            .get(proposal_id)
+            .unwrap_or_revert_with(Error::UnknownProposal);
+        proposals.remove(proposal_id);
+        Approvals::instance().set(proposal_id, Vec::new());
+        proposal
+    }
+}
+
+struct Proposals {
+    dict: Dict,
+}
+
+impl Proposals {
+    pub fn instance() -> Proposals {
+        Proposals {
+            dict: Dict::instance(PROPOSALS_DICT),
+        }
+    }
+
+    pub fn init() {
+        storage::new_dictionary(PROPOSALS_DICT).unwrap_or_revert();
+    }
+
+    pub fn get(&self, proposal_id: ProposalId) -> Option<Proposal> {
+        self.dict.get(&proposal_id.to_string())
+    }
+
+    pub fn set(&self, proposal_id: ProposalId, proposal: Proposal) {
+        self.dict.set(&proposal_id.to_string(), proposal);
+    }
+
+    pub fn remove(&self, proposal_id: ProposalId) {
+        self.dict.remove::<Proposal>(&proposal_id.to_string());
+    }
+}
+
+struct Approvals {
+    dict: Dict,
+}
+
+impl Approvals {
+    pub fn instance() -> Approvals {
+        Approvals {
+            dict: Dict::instance(APPROVALS_DICT),
+        }
+    }
+
+    pub fn init() {
+        storage::new_dictionary(APPROVALS_DICT).unwrap_or_revert();
+    }
+
+    pub fn get(&self, proposal_id: ProposalId) -> Vec<Key> {
+        self.dict.get(&proposal_id.to_string()).unwrap_or_default()
+    }
+
+    pub fn set(&self, proposal_id: ProposalId, approvers: Vec<Key>) {
+        if approvers.is_empty() {
+            self.dict.remove::<Vec<Key>>(&proposal_id.to_string());
+        } else {
+            self.dict.set(&proposal_id.to_string(), approvers);
+        }
+    }
+}
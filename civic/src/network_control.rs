@@ -0,0 +1,105 @@
+use alloc::string::{String, ToString};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::bytesrepr::ToBytes;
+use cep47::{
+    contract_utils::{ContractContext, ContractStorage, Dict},
+    TokenId,
+};
+
+pub type NetworkId = u64;
+
+pub const DEFAULT_NETWORK: NetworkId = 0;
+
+const TOKEN_NETWORKS_DICT: &str = "token_networks";
+const MULTIPLE_ACCOUNTS_DICT: &str = "network_multiple_accounts";
+
+pub trait NetworkControl<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(&mut self) {
+        TokenNetworks::init();
+        MultipleAccounts::init();
+    }
+
+    fn token_network(&self, token_id: &TokenId) -> NetworkId {
+        TokenNetworks::instance()
+            .get(token_id)
+            .unwrap_or(DEFAULT_NETWORK)
+    }
+
+    fn set_token_network(&mut self, token_id: &TokenId, network: NetworkId) {
+        TokenNetworks::instance().set(token_id, network);
+    }
+
+    fn remove_token_network(&mut self, token_id: &TokenId) {
+        TokenNetworks::instance().remove(token_id);
+    }
+
+    fn allows_multiple_accounts(&self, network: NetworkId) -> bool {
+        MultipleAccounts::instance().get(network)
+    }
+
+    fn set_allows_multiple_accounts(&mut self, network: NetworkId, allowed: bool) {
+        MultipleAccounts::instance().set(network, allowed);
+    }
+}
+
+struct TokenNetworks {
+    dict: Dict,
+}
+
+impl TokenNetworks {
+    pub fn instance() -> TokenNetworks {
+        TokenNetworks {
+            dict: Dict::instance(TOKEN_NETWORKS_DICT),
+        }
+    }
+
+    pub fn init() {
+        storage::new_dictionary(TOKEN_NETWORKS_DICT).unwrap_or_revert();
+    }
+
+    pub fn get(&self, token_id: &TokenId) -> Option<NetworkId> {
+        self.dict.get(token_id)
+    }
+
+    pub fn set(&self, token_id: &TokenId, network: NetworkId) {
+        self.dict.set(token_id, network);
+    }
+
+    pub fn remove(&self, token_id: &TokenId) {
+        self.dict.remove::<NetworkId>(token_id);
+    }
+}
+
+struct MultipleAccounts {
+    dict: Dict,
+}
+
+impl MultipleAccounts {
+    pub fn instance() -> MultipleAccounts {
+        MultipleAccounts {
+            dict: Dict::instance(MULTIPLE_ACCOUNTS_DICT),
+        }
+    }
+
+    pub fn init() {
+        storage::new_dictionary(MULTIPLE_ACCOUNTS_DICT).unwrap_or_revert();
+    }
+
+    pub fn get(&self, network: NetworkId) -> bool {
+        self.dict.get(&network.to_string()).unwrap_or_default()
+    }
+
+    pub fn set(&self, network: NetworkId, allowed: bool) {
+        self.dict.set(&network.to_string(), allowed);
+    }
+}
+
+pub fn network_and_value_to_str<T: ToBytes>(network: NetworkId, value: &T) -> String {
+    let mut bytes = network.to_bytes().unwrap_or_revert();
+    bytes.append(&mut value.to_bytes().unwrap_or_revert());
+    hex::encode(runtime::blake2b(bytes))
+}
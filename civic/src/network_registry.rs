@@ -0,0 +1,236 @@
+use alloc::string::{String, ToString};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{Key, U512};
+use cep47::contract_utils::{ContractContext, ContractStorage, Dict};
+
+use crate::{
+    error::Error,
+    network_control::{network_and_value_to_str, NetworkId},
+};
+
+/// Name, description and authority of a network. The authority manages the
+/// network's configuration and gatekeepers.
+pub type NetworkInfo = (String, String, Key);
+
+/// Default token lifetime in milliseconds (0 for tokens that never expire), whether
+/// tokens can be refreshed and whether an expired token may be removed.
+pub type TokenPolicy = (u64, bool, bool);
+
+/// Fees in motes a gatekeeper of the network charges to issue and to refresh a token.
+pub type FeePolicy = (U512, U512);
+
+const NETWORK_INFOS_DICT: &str = "network_infos";
+const TOKEN_POLICIES_DICT: &str = "network_token_policies";
+const FEE_POLICIES_DICT: &str = "network_fee_policies";
+const NETWORK_GATEKEEPERS_DICT: &str = "network_gatekeepers";
+const REFRESH_INTERVALS_DICT: &str = "network_refresh_intervals";
+
+pub trait NetworkRegistry<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(&mut self) {
+        NetworkInfos::init();
+        TokenPolicies::init();
+        FeePolicies::init();
+        NetworkGatekeepers::init();
+        RefreshIntervals::init();
+    }
+
+    fn network_info(&self, network: NetworkId) -> Option<NetworkInfo> {
+        NetworkInfos::instance().get(network)
+    }
+
+    fn register_network(&mut self, network: NetworkId, info: NetworkInfo) {
+        let network_infos = NetworkInfos::instance();
+        if network_infos.get(network).is_some() {
+            runtime::revert(Error::NetworkAlreadyRegistered);
+        }
+        network_infos.set(network, info);
+    }
+
+    fn set_network_info(&mut self, network: NetworkId, info: NetworkInfo) {
+        self.assert_network_registered(network);
+        NetworkInfos::instance().set(network, info);
+    }
+
+    fn network_authority(&self, network: NetworkId) -> Option<Key> {
+        self.network_info(network)
+            .map(|(_, _, authority)| authority)
+    }
+
+    fn assert_network_registered(&self, network: NetworkId) {
+        if self.network_info(network).is_none() {
+            runtime::revert(Error::UnknownNetwork);
+        }
+    }
+
+    fn network_token_policy(&self, network: NetworkId) -> TokenPolicy {
+        TokenPolicies::instance().get(network)
+    }
+
+    fn set_network_token_policy(&mut self, network: NetworkId, policy: TokenPolicy) {
+        self.assert_network_registered(network);
+        TokenPolicies::instance().set(network, policy);
+    }
+
+    fn network_fee_policy(&self, network: NetworkId) -> FeePolicy {
+        FeePolicies::instance().get(network)
+    }
+
+    fn set_network_fee_policy(&mut self, network: NetworkId, policy: FeePolicy) {
+        self.assert_network_registered(network);
+        FeePolicies::instance().set(network, policy);
+    }
+
+    /// Milliseconds after which tokens of the network need a refresh, 0 when they never do.
+    fn network_refresh_interval(&self, network: NetworkId) -> u64 {
+        RefreshIntervals::instance().get(network)
+    }
+
+    fn set_network_refresh_interval(&mut self, network: NetworkId, interval: u64) {
+        self.assert_network_registered(network);
+        RefreshIntervals::instance().set(network, interval);
+    }
+
+    fn is_network_gatekeeper(&self, network: NetworkId, gatekeeper: Key) -> bool {
+        NetworkGatekeepers::instance().is_gatekeeper(network, &gatekeeper)
+    }
+
+    fn add_network_gatekeeper(&mut self, network: NetworkId, gatekeeper: Key) {
+        self.assert_network_registered(network);
+        NetworkGatekeepers::instance().add_gatekeeper(network, &gatekeeper);
+    }
+
+    fn revoke_network_gatekeeper(&mut self, network: NetworkId, gatekeeper: Key) {
+        NetworkGatekeepers::instance().revoke_gatekeeper(network, &gatekeeper);
+    }
+}
+
+struct NetworkInfos {
+    dict: Dict,
+}
+
+impl NetworkInfos {
+    pub fn instance() -> NetworkInfos {
+        NetworkInfos {
+            dict: Dict::instance(NETWORK_INFOS_DICT),
+        }
+    }
+
+    pub fn init() {
+        storage::new_dictionary(NETWORK_INFOS_DICT).unwrap_or_revert();
+    }
+
+    pub fn get(&self, network: NetworkId) -> Option<NetworkInfo> {
+        self.dict.get(&network.to_string())
+    }
+
+    pub fn set(&self, network: NetworkId, info: NetworkInfo) {
+        self.dict.set(&network.to_string(), info);
+    }
+}
+
+struct TokenPolicies {
+    dict: Dict,
+}
+
+impl TokenPolicies {
+    pub fn instance() -> TokenPolicies {
+        TokenPolicies {
+            dict: Dict::instance(TOKEN_POLICIES_DICT),
+        }
+    }
+
+    pub fn init() {
+        storage::new_dictionary(TOKEN_POLICIES_DICT).unwrap_or_revert();
+    }
+
+    pub fn get(&self, network: NetworkId) -> TokenPolicy {
+        self.dict.get(&network.to_string()).unwrap_or_default()
+    }
+
+    pub fn set(&self, network: NetworkId, policy: TokenPolicy) {
+        self.dict.set(&network.to_string(), policy);
+    }
+}
+
+struct FeePolicies {
+    dict: Dict,
+}
+
+impl FeePolicies {
+    pub fn instance() -> FeePolicies {
+        FeePolicies {
+            dict: Dict::instance(FEE_POLICIES_DICT),
+        }
+    }
+
+    pub fn init() {
+        storage::new_dictionary(FEE_POLICIES_DICT).unwrap_or_revert();
+    }
+
+    pub fn get(&self, network: NetworkId) -> FeePolicy {
+        self.dict.get(&network.to_string()).unwrap_or_default()
+    }
+
+    pub fn set(&self, network: NetworkId, policy: FeePolicy) {
+        self.dict.set(&network.to_string(), policy);
+    }
+}
+
+struct RefreshIntervals {
+    dict: Dict,
+}
+
+impl RefreshIntervals {
+    pub fn instance() -> RefreshIntervals {
+        RefreshIntervals {
+            dict: Dict::instance(REFRESH_INTERVALS_DICT),
+        }
+    }
+
+    pub fn init() {
+        storage::new_dictionary(REFRESH_INTERVALS_DICT).unwrap_or_revert();
+    }
+
+    pub fn get(&self, network: NetworkId) -> u64 {
+        self.dict.get(&network.to_string()).unwrap_or_default()
+    }
+
+    pub fn set(&self, network: NetworkId, interval: u64) {
+        self.dict.set(&network.to_string(), interval);
+    }
+}
+
+struct NetworkGatekeepers {
+    dict: Dict,
+}
+
+impl NetworkGatekeepers {
+    pub fn instance() -> NetworkGatekeepers {
+        NetworkGatekeepers {
+            dict: Dict::instance(NETWORK_GATEKEEPERS_DICT),
+        }
+    }
+
+    pub fn init() {
+        storage::new_dictionary(NETWORK_GATEKEEPERS_DICT).unwrap_or_revert();
+    }
+
+    pub fn is_gatekeeper(&self, network: NetworkId, key: &Key) -> bool {
+        self.dict
+            .get::<()>(&network_and_value_to_str(network, key))
+            .is_some()
+    }
+
+    pub fn add_gatekeeper(&self, network: NetworkId, key: &Key) {
+        self.dict.set(&network_and_value_to_str(network, key), ());
+    }
+
+    pub fn revoke_gatekeeper(&self, network: NetworkId, key: &Key) {
+        self.dict
+            .remove::<()>(&network_and_value_to_str(network, key));
+    }
+}
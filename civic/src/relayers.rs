@@ -0,0 +1,51 @@
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{system::CallStackElement, Key};
+use cep47::contract_utils::{ContractContext, ContractStorage, Dict};
+
+const RELAYERS_DICT: &str = "gatekeeper_relayers";
+
+/// Relayer contracts gatekeepers operate through, and the account that signed
+/// the deploy calling through them.
+pub trait Relayers<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(&mut self) {
+        storage::new_dictionary(RELAYERS_DICT).unwrap_or_revert();
+    }
+
+    /// Account that signed the deploy, however many contracts it went through.
+    /// Mirrors `get_origin_caller` of contract-utils, which the cep47 context lacks.
+    fn origin_caller(&self) -> Key {
+        let call_stack = self.storage().call_stack();
+        element_to_key(call_stack.first().unwrap_or_revert())
+    }
+
+    /// Whether `gatekeeper` (an identity) has registered `relayer` to forward its calls.
+    fn is_relayer(&self, relayer: Key, gatekeeper: Key) -> bool {
+        Dict::instance(RELAYERS_DICT)
+            .get_by_keys::<()>((&relayer, &gatekeeper))
+            .is_some()
+    }
+
+    fn set_relayer(&mut self, relayer: Key, gatekeeper: Key, allowed: bool) {
+        let relayers = Dict::instance(RELAYERS_DICT);
+        if allowed {
+            relayers.set_by_keys((&relayer, &gatekeeper), ());
+        } else {
+            relayers.remove_by_vec_of_keys::<()>((&relayer, &gatekeeper));
+        }
+    }
+}
+
+fn element_to_key(element: &CallStackElement) -> Key {
+    match element {
+        CallStackElement::Session { account_hash } => (*account_hash).into(),
+        CallStackElement::StoredSession {
+            account_hash,
+            contract_package_hash: _,
+            contract_hash: _,
+        } => (*account_hash).into(),
+        CallStackElement::StoredContract {
+            contract_package_hash,
+            contract_hash: _,
+        } => (*contract_package_hash).into(),
+    }
+}
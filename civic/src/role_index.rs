@@ -0,0 +1,35 @@
+use casper_types::Key;
+use cep47::contract_utils::{ContractContext, ContractStorage};
+
+use crate::indexed_set::IndexedSet;
+
+const ADMINS_BY_INDEX_DICT: &str = "admins_by_index";
+const ADMIN_INDICES_DICT: &str = "admin_indices";
+const ADMIN_COUNT: &str = "admin_count";
+
+/// Index of the admins kept alongside the `admins` dictionary of `AdminControl`.
+pub trait AdminIndex<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(&mut self) {
+        IndexedSet::<Key>::init(ADMINS_BY_INDEX_DICT, ADMIN_INDICES_DICT, ADMIN_COUNT);
+    }
+
+    fn admin_count(&self) -> u64 {
+        admins().count()
+    }
+
+    fn admin_at(&self, index: u64) -> Option<Key> {
+        admins().at(index)
+    }
+
+    fn index_admin(&mut self, admin: Key) {
+        admins().insert(admin);
+    }
+
+    fn unindex_admin(&mut self, admin: Key) {
+        admins().remove(&admin);
+    }
+}
+
+fn admins() -> IndexedSet<Key> {
+    IndexedSet::instance(ADMINS_BY_INDEX_DICT, ADMIN_INDICES_DICT, ADMIN_COUNT)
+}
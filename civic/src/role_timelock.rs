@@ -0,0 +1,176 @@
+use alloc::string::{String, ToString};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::Key;
+use cep47::contract_utils::{get_key, set_key, ContractContext, ContractStorage, Dict};
+
+use crate::error::Error;
+
+pub type RoleChangeId = u64;
+
+/// Role entry point to run (e.g. `grant_admin`), the account it applies to and
+/// the block time from which it can be executed. A scheduled `set_role_change_delay`
+/// applies to the admin who scheduled it and keeps the new delay alongside.
+pub type RoleChange = (String, Key, u64);
+
+pub const SET_ROLE_CHANGE_DELAY: &str = "set_role_change_delay";
+
+pub const ROLE_CHANGES: [&str; 4] = [
+    "grant_admin",
+    "revoke_admin",
+    "grant_gatekeeper",
+    "revoke_gatekeeper",
+];
+
+const ROLE_CHANGES_DICT: &str = "scheduled_role_changes";
+const ROLE_CHANGE_PROPOSERS_DICT: &str = "role_change_proposers";
+const ROLE_CHANGE_DELAYS_DICT: &str = "scheduled_role_change_delays";
+const ROLE_CHANGE_COUNT: &str = "role_change_count";
+const ROLE_CHANGE_DELAY: &str = "role_change_delay";
+
+pub trait RoleTimelock<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(&mut self) {
+        ScheduledRoleChanges::init();
+        set_key(ROLE_CHANGE_COUNT, RoleChangeId::default());
+        set_key(ROLE_CHANGE_DELAY, 0u64);
+    }
+
+    /// Delay in milliseconds of block time, zero applying role changes instantly.
+    fn role_change_delay(&self) -> u64 {
+        get_key(ROLE_CHANGE_DELAY).unwrap_or_default()
+    }
+
+    fn set_role_change_delay(&mut self, delay: u64) {
+        set_key(ROLE_CHANGE_DELAY, delay);
+    }
+
+    fn scheduled_role_change(&self, change_id: RoleChangeId) -> Option<RoleChange> {
+        ScheduledRoleChanges::instance().get(change_id)
+    }
+
+    /// Admin who scheduled the change, who may not cancel it.
+    fn role_change_proposer(&self, change_id: RoleChangeId) -> Option<Key> {
+        ScheduledRoleChanges::instance().proposer(change_id)
+    }
+
+    /// Delay a scheduled `set_role_change_delay` sets once executed.
+    fn scheduled_role_change_delay(&self, change_id: RoleChangeId) -> Option<u64> {
+        ScheduledRoleChanges::instance().delay(change_id)
+    }
+
+    fn schedule_role_change(
+        &mut self,
+        change: String,
+        account: Key,
+        proposer: Key,
+        now: u64,
+    ) -> RoleChangeId {
+        if !ROLE_CHANGES.contains(&change.as_str()) {
+            runtime::revert(Error::UnknownAction);
+        }
+        self.push_role_change(change, account, proposer, now)
+    }
+
+    fn schedule_role_change_delay(&mut self, delay: u64, proposer: Key, now: u64) -> RoleChangeId {
+        let change_id =
+            self.push_role_change(SET_ROLE_CHANGE_DELAY.into(), proposer, proposer, now);
+        ScheduledRoleChanges::instance().set_delay(change_id, delay);
+        change_id
+    }
+
+    fn push_role_change(
+        &mut self,
+        change: String,
+        account: Key,
+        proposer: Key,
+        now: u64,
+    ) -> RoleChangeId {
+        let change_id: RoleChangeId = get_key(ROLE_CHANGE_COUNT).unwrap_or_default();
+        let executable_at = now.saturating_add(self.role_change_delay());
+        let role_changes = ScheduledRoleChanges::instance();
+        role_changes.set(change_id, (change, account, executable_at));
+        role_changes.set_proposer(change_id, proposer);
+        set_key(ROLE_CHANGE_COUNT, change_id + 1);
+        change_id
+    }
+
+    /// Removes a scheduled change once its delay has passed, so that it runs exactly once.
+    fn take_role_change(&mut self, change_id: RoleChangeId, now: u64) -> RoleChange {
+        let role_changes = ScheduledRoleChanges::instance();
+        let role_change = role_changes
+            .get(change_id)
+            .unwrap_or_revert_with(Error::UnknownRoleChange);
+        if now < role_change.2 {
+            runtime::revert(Error::RoleChangeLocked);
+        }
+        role_changes.remove(change_id);
+        role_change
+    }
+
+    /// Drops a scheduled change, which needs an admin other than the one who scheduled it.
+    fn cancel_role_change(&mut self, change_id: RoleChangeId, admin: Key) {
+        let role_changes = ScheduledRoleChanges::instance();
+        role_changes
+            .get(change_id)
+            .unwrap_or_revert_with(Error::UnknownRoleChange);
+        if role_changes.proposer(change_id) == Some(admin) {
+            runtime::revert(Error::PermissionDenied);
+        }
+        role_changes.remove(change_id);
+    }
+}
+
+struct ScheduledRoleChanges {
+    dict: Dict,
+    proposers_dict: Dict,
+    delays_dict: Dict,
+}
+
+impl ScheduledRoleChanges {
+    pub fn instance() -> ScheduledRoleChanges {
+        ScheduledRoleChanges {
+            dict: Dict::instance(ROLE_CHANGES_DICT),
+            proposers_dict: Dict::instance(ROLE_CHANGE_PROPOSERS_DICT),
+            delays_dict: Dict::instance(ROLE_CHANGE_DELAYS_DICT),
+        }
+    }
+
+    pub fn init() {
+        storage::new_dictionary(ROLE_CHANGES_DICT).unwrap_or_revert();
+        storage::new_dictionary(ROLE_CHANGE_PROPOSERS_DICT).unwrap_or_revert();
+        storage::new_dictionary(ROLE_CHANGE_DELAYS_DICT).unwrap_or_revert();
+    }
+
+    pub fn get(&self, change_id: RoleChangeId) -> Option<RoleChange> {
+        self.dict.get(&change_id.to_string())
+    }
+
+    pub fn set(&self, change_id: RoleChangeId, role_change: RoleChange) {
+        self.dict.set(&change_id.to_string(), role_change);
+    }
+
+    pub fn proposer(&self, change_id: RoleChangeId) -> Option<Key> {
+        self.proposers_dict.get(&change_id.to_string())
+    }
+
+    pub fn set_proposer(&self, change_id: RoleChangeId, proposer: Key) {
+        self.proposers_dict.set(&change_id.to_string(), proposer);
+    }
+
+    pub fn delay(&self, change_id: RoleChangeId) -> Option<u64> {
+        self.delays_dict.get(&change_id.to_string())
+    }
+
+    pub fn set_delay(&self, change_id: RoleChangeId, delay: u64) {
+        self.delays_dict.set(&change_id.to_string(), delay);
+    }
+
+    pub fn remove(&self, change_id: RoleChangeId) {
+        self.dict.remove::<RoleChange>(&change_id.to_string());
+        self.proposers_dict.remove::<Key>(&change_id.to_string());
+        self.delays_dict.remove::<u64>(&change_id.to_string());
+    }
+}
@@ -0,0 +1,83 @@
+//! Pull queue of token notifications for subscribed contracts.
+//!
+//! Casper can neither cap the gas of a contract call nor catch its revert, so
+//! calling subscribers from `freeze`, `revoke` and `burn` would let any of them
+//! make those actions fail or cost without bound. The actions only append to a
+//! log instead, and `deliver_notifications` later runs a bounded batch of one
+//! subscriber's callbacks, moving its cursor past the delivered entries.
+
+use alloc::string::{String, ToString};
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::Key;
+use cep47::{
+    contract_utils::{get_key, set_key, ContractContext, ContractStorage, Dict},
+    TokenId,
+};
+
+use crate::token_actions::ActionReason;
+
+/// Largest number of callbacks run by a single `deliver_notifications` call.
+pub const MAX_NOTIFICATION_BATCH: u64 = 10;
+
+/// Account of a token, the token and the action (`freeze`, `revoke` or `burn`)
+/// taken on it with its reason code.
+pub type Notification = (Key, TokenId, (String, ActionReason));
+
+const NOTIFICATIONS_DICT: &str = "notifications";
+const NOTIFICATION_COUNT: &str = "notification_count";
+const CALLBACKS_DICT: &str = "subscriber_callbacks";
+const CURSORS_DICT: &str = "subscriber_cursors";
+
+/// Append-only log of token holds and burns, and the position each subscribed
+/// contract package has been notified up to.
+pub trait Subscriptions<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(&mut self) {
+        storage::new_dictionary(NOTIFICATIONS_DICT).unwrap_or_revert();
+        set_key(NOTIFICATION_COUNT, 0u64);
+        storage::new_dictionary(CALLBACKS_DICT).unwrap_or_revert();
+        storage::new_dictionary(CURSORS_DICT).unwrap_or_revert();
+    }
+
+    fn notification_count(&self) -> u64 {
+        get_key(NOTIFICATION_COUNT).unwrap_or_default()
+    }
+
+    fn notification(&self, index: u64) -> Option<Notification> {
+        Dict::instance(NOTIFICATIONS_DICT).get(&index.to_string())
+    }
+
+    fn push_notification(&mut self, notification: Notification) {
+        let count = self.notification_count();
+        Dict::instance(NOTIFICATIONS_DICT).set(&count.to_string(), notification);
+        set_key(NOTIFICATION_COUNT, count + 1);
+    }
+
+    fn subscriber_callback(&self, subscriber: Key) -> Option<String> {
+        Dict::instance(CALLBACKS_DICT).get_by_key(&subscriber)
+    }
+
+    /// Index of the next notification the subscriber is due.
+    fn subscriber_cursor(&self, subscriber: Key) -> Option<u64> {
+        Dict::instance(CURSORS_DICT).get_by_key(&subscriber)
+    }
+
+    fn set_subscriber_cursor(&mut self, subscriber: Key, cursor: u64) {
+        Dict::instance(CURSORS_DICT).set_by_key(&subscriber, cursor);
+    }
+
+    /// Subscribes from the next notification on, keeping the cursor of a
+    /// subscriber that only changes its callback.
+    fn subscribe(&mut self, subscriber: Key, entry_point: String) {
+        if self.subscriber_cursor(subscriber).is_none() {
+            let cursor = self.notification_count();
+            self.set_subscriber_cursor(subscriber, cursor);
+        }
+        Dict::instance(CALLBACKS_DICT).set_by_key(&subscriber, entry_point);
+    }
+
+    fn unsubscribe(&mut self, subscriber: Key) {
+        Dict::instance(CALLBACKS_DICT).remove_by_key::<String>(&subscriber);
+        Dict::instance(CURSORS_DICT).remove_by_key::<u64>(&subscriber);
+    }
+}
@@ -0,0 +1,27 @@
+use casper_types::Key;
+use cep47::contract_utils::{get_key, set_key, ContractContext, ContractStorage};
+
+const SUCCESSOR: &str = "successor";
+const FORWARD_READS: &str = "forward_reads";
+
+/// Retirement of the contract in favour of a successor contract package. Both
+/// named keys only exist once the contract was sunset.
+pub trait ContractSunset<Storage: ContractStorage>: ContractContext<Storage> {
+    fn successor(&self) -> Option<Key> {
+        get_key(SUCCESSOR)
+    }
+
+    /// Successor that `is_kyc_proved` and `verify` are forwarded to.
+    fn read_forwarding_target(&self) -> Option<Key> {
+        if get_key(FORWARD_READS).unwrap_or_default() {
+            self.successor()
+        } else {
+            None
+        }
+    }
+
+    fn sunset(&mut self, successor: Key, forward_reads: bool) {
+        set_key(SUCCESSOR, successor);
+        set_key(FORWARD_READS, forward_reads);
+    }
+}
@@ -0,0 +1,80 @@
+use alloc::{string::String, vec::Vec};
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use cep47::{
+    contract_utils::{ContractContext, ContractStorage, Dict},
+    TokenId,
+};
+
+use crate::token_stamps::Stamp;
+
+/// Structured reason code of a freeze or revocation.
+pub type ActionReason = u8;
+
+/// Reason code recorded for disputes and their resolutions.
+pub const NO_REASON: ActionReason = 0;
+
+/// Action taken on a token (`freeze`, `unfreeze`, `revoke`, `dispute`, `reinstate`,
+/// `confirm` or `burn`), its reason code and who took it when.
+pub type TokenAction = (String, ActionReason, Stamp);
+
+/// Evidence hash submitted by the holder and when it was submitted.
+pub type Dispute = ([u8; 32], Stamp);
+
+const ACTIONS_DICT: &str = "token_actions";
+const HELD_STATUSES_DICT: &str = "held_token_statuses";
+const DISPUTES_DICT: &str = "token_disputes";
+
+/// History of freezes, revocations, disputes and burns of each token.
+pub trait TokenActions<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(&mut self) {
+        storage::new_dictionary(ACTIONS_DICT).unwrap_or_revert();
+        storage::new_dictionary(HELD_STATUSES_DICT).unwrap_or_revert();
+        storage::new_dictionary(DISPUTES_DICT).unwrap_or_revert();
+    }
+
+    fn token_actions(&self, token_id: &TokenId) -> Vec<TokenAction> {
+        Dict::instance(ACTIONS_DICT)
+            .get(token_id)
+            .unwrap_or_default()
+    }
+
+    fn record_action(&mut self, token_id: &TokenId, action: TokenAction) {
+        let mut actions = self.token_actions(token_id);
+        actions.push(action);
+        Dict::instance(ACTIONS_DICT).set(token_id, actions);
+    }
+
+    /// Status a frozen or revoked token had before it was first held.
+    fn held_status(&self, token_id: &TokenId) -> Option<String> {
+        Dict::instance(HELD_STATUSES_DICT).get(token_id)
+    }
+
+    fn set_held_status(&mut self, token_id: &TokenId, status: Option<String>) {
+        let held_statuses = Dict::instance(HELD_STATUSES_DICT);
+        match status {
+            Some(status) => held_statuses.set(token_id, status),
+            None => held_statuses.remove::<String>(token_id),
+        }
+    }
+
+    fn open_dispute(&self, token_id: &TokenId) -> Option<Dispute> {
+        Dict::instance(DISPUTES_DICT).get(token_id)
+    }
+
+    fn set_open_dispute(&mut self, token_id: &TokenId, dispute: Option<Dispute>) {
+        let disputes = Dict::instance(DISPUTES_DICT);
+        match dispute {
+            Some(dispute) => disputes.set(token_id, dispute),
+            None => disputes.remove::<Dispute>(token_id),
+        }
+    }
+
+    /// Closes the hold and dispute of a burned token, keeping its history with a
+    /// final `burn` action.
+    fn close_token_actions(&mut self, token_id: &TokenId, stamp: Stamp) {
+        self.record_action(token_id, ("burn".into(), NO_REASON, stamp));
+        self.set_held_status(token_id, None);
+        self.set_open_dispute(token_id, None);
+    }
+}
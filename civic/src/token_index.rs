@@ -0,0 +1,40 @@
+use cep47::{
+    contract_utils::{ContractContext, ContractStorage},
+    TokenId,
+};
+
+use crate::indexed_set::IndexedSet;
+
+/// Largest page returned by the paged token listings.
+pub const MAX_PAGE_SIZE: u64 = 50;
+
+const TOKENS_BY_INDEX_DICT: &str = "tokens_by_index";
+const TOKEN_INDICES_DICT: &str = "token_indices";
+const TOKEN_COUNT: &str = "token_count";
+
+/// Global index of the live tokens, whatever their owner.
+pub trait TokenIndex<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(&mut self) {
+        IndexedSet::<TokenId>::init(TOKENS_BY_INDEX_DICT, TOKEN_INDICES_DICT, TOKEN_COUNT);
+    }
+
+    fn token_count(&self) -> u64 {
+        tokens().count()
+    }
+
+    fn token_at(&self, index: u64) -> Option<TokenId> {
+        tokens().at(index)
+    }
+
+    fn index_token(&mut self, token_id: TokenId) {
+        tokens().insert(token_id);
+    }
+
+    fn unindex_token(&mut self, token_id: &TokenId) {
+        tokens().remove(token_id);
+    }
+}
+
+fn tokens() -> IndexedSet<TokenId> {
+    IndexedSet::instance(TOKENS_BY_INDEX_DICT, TOKEN_INDICES_DICT, TOKEN_COUNT)
+}
@@ -0,0 +1,107 @@
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::Key;
+use cep47::{
+    contract_utils::{ContractContext, ContractStorage, Dict},
+    TokenId,
+};
+
+/// Block time of a change and the caller that made it.
+pub type Stamp = (u64, Key);
+
+/// Issuance and last update of a token.
+pub type TokenStamps = (Stamp, Stamp);
+
+const TOKEN_STAMPS_DICT: &str = "token_stamps";
+const TOKEN_REFRESHES_DICT: &str = "token_refreshes";
+
+/// Audit stamps written by the contract on mint and on every change of a token.
+pub trait TokenStampRegistry<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(&mut self) {
+        Stamps::init();
+        Refreshes::init();
+    }
+
+    fn token_stamps(&self, token_id: &TokenId) -> Option<TokenStamps> {
+        Stamps::instance().get(token_id)
+    }
+
+    fn stamp_issuance(&mut self, token_id: &TokenId, stamp: Stamp) {
+        Stamps::instance().set(token_id, (stamp, stamp));
+    }
+
+    fn stamp_update(&mut self, token_id: &TokenId, stamp: Stamp) {
+        let stamps = Stamps::instance();
+        let (issued, _) = stamps.get(token_id).unwrap_or((stamp, stamp));
+        stamps.set(token_id, (issued, stamp));
+    }
+
+    /// Block time of the token's mint or latest refresh.
+    fn last_refreshed(&self, token_id: &TokenId) -> Option<u64> {
+        Refreshes::instance().get(token_id)
+    }
+
+    fn stamp_refresh(&mut self, token_id: &TokenId, now: u64) {
+        Refreshes::instance().set(token_id, now);
+    }
+
+    fn remove_token_stamps(&mut self, token_id: &TokenId) {
+        Stamps::instance().remove(token_id);
+        Refreshes::instance().remove(token_id);
+    }
+}
+
+struct Stamps {
+    dict: Dict,
+}
+
+impl Stamps {
+    pub fn instance() -> Stamps {
+        Stamps {
+            dict: Dict::instance(TOKEN_STAMPS_DICT),
+        }
+    }
+
+    pub fn init() {
+        storage::new_dictionary(TOKEN_STAMPS_DICT).unwrap_or_revert();
+    }
+
+    pub fn get(&self, token_id: &TokenId) -> Option<TokenStamps> {
+        self.dict.get(token_id)
+    }
+
+    pub fn set(&self, token_id: &TokenId, stamps: TokenStamps) {
+        self.dict.set(token_id, stamps);
+    }
+
+    pub fn remove(&self, token_id: &TokenId) {
+        self.dict.remove::<TokenStamps>(token_id);
+    }
+}
+
+struct Refreshes {
+    dict: Dict,
+}
+
+impl Refreshes {
+    pub fn instance() -> Refreshes {
+        Refreshes {
+            dict: Dict::instance(TOKEN_REFRESHES_DICT),
+        }
+    }
+
+    pub fn init() {
+        storage::new_dictionary(TOKEN_REFRESHES_DICT).unwrap_or_revert();
+    }
+
+    pub fn get(&self, token_id: &TokenId) -> Option<u64> {
+        self.dict.get(token_id)
+    }
+
+    pub fn set(&self, token_id: &TokenId, refreshed_at: u64) {
+        self.dict.set(token_id, refreshed_at);
+    }
+
+    pub fn remove(&self, token_id: &TokenId) {
+        self.dict.remove::<u64>(token_id);
+    }
+}
@@ -0,0 +1,66 @@
+use alloc::collections::BTreeMap;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::Key;
+use cep47::contract_utils::{ContractContext, ContractStorage, Dict};
+
+use crate::{error::Error, indexed_set::IndexedSet, network_control::NetworkId};
+
+/// Number of gateway hops a verification may fan out through.
+pub const MAX_FEDERATION_DEPTH: u8 = 2;
+
+/// Number of gateways a verification may fan out to at each hop.
+pub const MAX_TRUSTED_GATEWAYS: u64 = 5;
+
+/// Network of the external gateway that each local network maps to.
+pub type NetworkMapping = BTreeMap<NetworkId, NetworkId>;
+
+const GATEWAYS_BY_INDEX_DICT: &str = "trusted_gateways_by_index";
+const GATEWAY_INDICES_DICT: &str = "trusted_gateway_indices";
+const GATEWAY_COUNT: &str = "trusted_gateway_count";
+const GATEWAY_NETWORKS_DICT: &str = "trusted_gateway_networks";
+
+/// Other gateway token contract packages whose credentials this contract accepts.
+pub trait TrustedGateways<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(&mut self) {
+        IndexedSet::<Key>::init(GATEWAYS_BY_INDEX_DICT, GATEWAY_INDICES_DICT, GATEWAY_COUNT);
+        storage::new_dictionary(GATEWAY_NETWORKS_DICT).unwrap_or_revert();
+    }
+
+    fn trusted_gateway_count(&self) -> u64 {
+        gateways().count()
+    }
+
+    fn trusted_gateway_at(&self, index: u64) -> Option<Key> {
+        gateways().at(index)
+    }
+
+    fn gateway_networks(&self, gateway: Key) -> NetworkMapping {
+        Dict::instance(GATEWAY_NETWORKS_DICT)
+            .get_by_key(&gateway)
+            .unwrap_or_default()
+    }
+
+    /// Trusts a new gateway while fewer than `MAX_TRUSTED_GATEWAYS` are, or
+    /// replaces the network mapping of a trusted one.
+    fn trust_gateway(&mut self, gateway: Key, networks: NetworkMapping) {
+        let gateways = gateways();
+        if !gateways.contains(&gateway) && gateways.count() >= MAX_TRUSTED_GATEWAYS {
+            runtime::revert(Error::TooManyGateways);
+        }
+        gateways.insert(gateway);
+        Dict::instance(GATEWAY_NETWORKS_DICT).set_by_key(&gateway, networks);
+    }
+
+    fn distrust_gateway(&mut self, gateway: Key) {
+        gateways().remove(&gateway);
+        Dict::instance(GATEWAY_NETWORKS_DICT).remove_by_key::<NetworkMapping>(&gateway);
+    }
+}
+
+fn gateways() -> IndexedSet<Key> {
+    IndexedSet::instance(GATEWAYS_BY_INDEX_DICT, GATEWAY_INDICES_DICT, GATEWAY_COUNT)
+}
@@ -0,0 +1,12 @@
+//! Reason codes returned by `verify` next to the verification outcome.
+
+pub type Reason = u8;
+
+pub const VERIFIED: Reason = 0;
+pub const NO_TOKEN: Reason = 1;
+pub const INACTIVE: Reason = 2;
+pub const BLOCKED: Reason = 3;
+pub const EXPIRED: Reason = 4;
+pub const JURISDICTION_NOT_ALLOWED: Reason = 5;
+pub const ISSUER_COMPROMISED: Reason = 6;
+pub const NEEDS_REFRESH: Reason = 7;
@@ -0,0 +1,119 @@
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::Key;
+use cep47::{
+    contract_utils::{ContractContext, ContractStorage, Dict},
+    TokenId,
+};
+
+use crate::{
+    error::Error,
+    network_control::{network_and_value_to_str, NetworkId},
+};
+
+/// Ordered verification tier of a token, e.g. basic KYC below enhanced due diligence.
+pub type Level = u8;
+
+pub const BASIC_LEVEL: Level = 0;
+
+const TOKEN_LEVELS_DICT: &str = "token_levels";
+const LEVEL_CAPS_DICT: &str = "gatekeeper_level_caps";
+
+pub trait VerificationLevels<Storage: ContractStorage>: ContractContext<Storage> {
+    fn init(&mut self) {
+        TokenLevels::init();
+        LevelCaps::init();
+    }
+
+    fn token_level(&self, token_id: &TokenId) -> Level {
+        TokenLevels::instance().get(token_id)
+    }
+
+    fn set_token_level(&mut self, token_id: &TokenId, level: Level) {
+        TokenLevels::instance().set(token_id, level);
+    }
+
+    fn remove_token_level(&mut self, token_id: &TokenId) {
+        TokenLevels::instance().remove(token_id);
+    }
+
+    fn gatekeeper_level_cap(&self, network: NetworkId, gatekeeper: Key) -> Option<Level> {
+        LevelCaps::instance().get(network, &gatekeeper)
+    }
+
+    fn set_gatekeeper_level_cap(
+        &mut self,
+        network: NetworkId,
+        gatekeeper: Key,
+        max_level: Option<Level>,
+    ) {
+        LevelCaps::instance().set(network, &gatekeeper, max_level);
+    }
+
+    fn assert_level_allowed(&self, network: NetworkId, gatekeeper: Key, level: Level) {
+        if let Some(max_level) = self.gatekeeper_level_cap(network, gatekeeper) {
+            if level > max_level {
+                runtime::revert(Error::LevelNotAllowed);
+            }
+        }
+    }
+}
+
+struct TokenLevels {
+    dict: Dict,
+}
+
+impl TokenLevels {
+    pub fn instance() -> TokenLevels {
+        TokenLevels {
+            dict: Dict::instance(TOKEN_LEVELS_DICT),
+        }
+    }
+
+    pub fn init() {
+        storage::new_dictionary(TOKEN_LEVELS_DICT).unwrap_or_revert();
+    }
+
+    pub fn get(&self, token_id: &TokenId) -> Level {
+        self.dict.get(token_id).unwrap_or(BASIC_LEVEL)
+    }
+
+    pub fn set(&self, token_id: &TokenId, level: Level) {
+        self.dict.set(token_id, level);
+    }
+
+    pub fn remove(&self, token_id: &TokenId) {
+        self.dict.remove::<Level>(token_id);
+    }
+}
+
+struct LevelCaps {
+    dict: Dict,
+}
+
+impl LevelCaps {
+    pub fn instance() -> LevelCaps {
+        LevelCaps {
+            dict: Dict::instance(LEVEL_CAPS_DICT),
+        }
+    }
+
+    pub fn init() {
+        storage::new_dictionary(LEVEL_CAPS_DICT).unwrap_or_revert();
+    }
+
+    pub fn get(&self, network: NetworkId, gatekeeper: &Key) -> Option<Level> {
+        self.dict
+            .get(&network_and_value_to_str(network, gatekeeper))
+    }
+
+    pub fn set(&self, network: NetworkId, gatekeeper: &Key, max_level: Option<Level>) {
+        let key = network_and_value_to_str(network, gatekeeper);
+        match max_level {
+            Some(max_level) => self.dict.set(&key, max_level),
+            None => self.dict.remove::<Level>(&key),
+        }
+    }
+}
@@ -0,0 +1,394 @@
+//! Consumer of the KYC contract used by the tests. It stores the answers of the
+//! KYC contract's read entry points and the notifications it receives as named
+//! keys, since deploys cannot return values to the test harness.
+#![no_main]
+#![no_std]
+#[macro_use]
+extern crate alloc;
+
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::convert::TryInto;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    bytesrepr::{FromBytes, ToBytes},
+    runtime_args, CLType, CLTyped, ContractPackageHash, EntryPoint, EntryPointAccess,
+    EntryPointType, EntryPoints, Key, Parameter, RuntimeArgs, U256,
+};
+
+/// Account, token, action and reason code sent by the KYC contract.
+type Notification = (Key, String, (String, u8));
+/// Token with its status, expiry and network, as listed by the KYC contract.
+type TokenSummary = (String, (String, Option<u64>, u64));
+
+const LAST_VERIFICATION: &str = "last_verification";
+const LAST_KYC_PROVED: &str = "last_kyc_proved";
+const LAST_ELIGIBILITY: &str = "last_eligibility";
+const LAST_CLAIM_VERIFIED: &str = "last_claim_verified";
+const LAST_TOKENS: &str = "last_tokens";
+const LAST_COMPROMISED_TOKENS: &str = "last_compromised_tokens";
+const NOTIFICATIONS: &str = "notifications";
+
+#[no_mangle]
+fn verify() {
+    let gateway = runtime::get_named_arg::<Key>("gateway");
+    let account = runtime::get_named_arg::<Key>("account");
+    let index = runtime::get_named_arg::<Option<U256>>("index");
+    let ret: (bool, u8) = runtime::call_versioned_contract(
+        package_hash(gateway),
+        None,
+        "verify",
+        runtime_args! {
+            "account" => account,
+            "index" => index,
+        },
+    );
+    set_key(LAST_VERIFICATION, ret);
+}
+
+#[no_mangle]
+fn verify_fresh() {
+    let gateway = runtime::get_named_arg::<Key>("gateway");
+    let account = runtime::get_named_arg::<Key>("account");
+    let index = runtime::get_named_arg::<Option<U256>>("index");
+    let ret: (bool, u8) = runtime::call_versioned_contract(
+        package_hash(gateway),
+        None,
+        "verify_fresh",
+        runtime_args! {
+            "account" => account,
+            "index" => index,
+        },
+    );
+    set_key(LAST_VERIFICATION, ret);
+}
+
+#[no_mangle]
+fn is_kyc_proved() {
+    let gateway = runtime::get_named_arg::<Key>("gateway");
+    let account = runtime::get_named_arg::<Key>("account");
+    let index = runtime::get_named_arg::<Option<U256>>("index");
+    let ret: bool = runtime::call_versioned_contract(
+        package_hash(gateway),
+        None,
+        "is_kyc_proved",
+        runtime_args! {
+            "account" => account,
+            "index" => index,
+        },
+    );
+    set_key(LAST_KYC_PROVED, ret);
+}
+
+#[no_mangle]
+fn is_kyc_proved_at_level() {
+    let gateway = runtime::get_named_arg::<Key>("gateway");
+    let account = runtime::get_named_arg::<Key>("account");
+    let level = runtime::get_named_arg::<u8>("level");
+    let ret: bool = runtime::call_versioned_contract(
+        package_hash(gateway),
+        None,
+        "is_kyc_proved_at_level",
+        runtime_args! {
+            "account" => account,
+            "level" => level,
+            "index" => Option::<U256>::None,
+        },
+    );
+    set_key(LAST_KYC_PROVED, ret);
+}
+
+#[no_mangle]
+fn is_eligible() {
+    let gateway = runtime::get_named_arg::<Key>("gateway");
+    let account = runtime::get_named_arg::<Key>("account");
+    let policy_id = runtime::get_named_arg::<u64>("policy_id");
+    let ret: (bool, u8) = runtime::call_versioned_contract(
+        package_hash(gateway),
+        None,
+        "is_eligible",
+        runtime_args! {
+            "account" => account,
+            "policy_id" => policy_id,
+        },
+    );
+    set_key(LAST_ELIGIBILITY, ret);
+}
+
+#[no_mangle]
+fn verify_claim() {
+    let gateway = runtime::get_named_arg::<Key>("gateway");
+    let token_id = runtime::get_named_arg::<String>("token_id");
+    let claim = runtime::get_named_arg::<String>("claim");
+    let value = runtime::get_named_arg::<String>("value");
+    let salt = runtime::get_named_arg::<String>("salt");
+    let ret: bool = runtime::call_versioned_contract(
+        package_hash(gateway),
+        None,
+        "verify_claim",
+        runtime_args! {
+            "token_id" => token_id,
+            "claim" => claim,
+            "value" => value,
+            "salt" => salt,
+        },
+    );
+    set_key(LAST_CLAIM_VERIFIED, ret);
+}
+
+#[no_mangle]
+fn tokens_of() {
+    let gateway = runtime::get_named_arg::<Key>("gateway");
+    let owner = runtime::get_named_arg::<Key>("owner");
+    let offset = runtime::get_named_arg::<u64>("offset");
+    let limit = runtime::get_named_arg::<u64>("limit");
+    let ret: Vec<TokenSummary> = runtime::call_versioned_contract(
+        package_hash(gateway),
+        None,
+        "tokens_of",
+        runtime_args! {
+            "owner" => owner,
+            "offset" => offset,
+            "limit" => limit,
+        },
+    );
+    set_key(LAST_TOKENS, ret);
+}
+
+#[no_mangle]
+fn tokens() {
+    let gateway = runtime::get_named_arg::<Key>("gateway");
+    let offset = runtime::get_named_arg::<u64>("offset");
+    let limit = runtime::get_named_arg::<u64>("limit");
+    let ret: Vec<TokenSummary> = runtime::call_versioned_contract(
+        package_hash(gateway),
+        None,
+        "tokens",
+        runtime_args! {
+            "offset" => offset,
+            "limit" => limit,
+        },
+    );
+    set_key(LAST_TOKENS, ret);
+}
+
+#[no_mangle]
+fn compromised_tokens() {
+    let gateway = runtime::get_named_arg::<Key>("gateway");
+    let gatekeeper = runtime::get_named_arg::<Key>("gatekeeper");
+    let offset = runtime::get_named_arg::<u64>("offset");
+    let limit = runtime::get_named_arg::<u64>("limit");
+    let ret: Vec<String> = runtime::call_versioned_contract(
+        package_hash(gateway),
+        None,
+        "compromised_tokens",
+        runtime_args! {
+            "gatekeeper" => gatekeeper,
+            "offset" => offset,
+            "limit" => limit,
+        },
+    );
+    set_key(LAST_COMPROMISED_TOKENS, ret);
+}
+
+#[no_mangle]
+fn subscribe() {
+    let gateway = runtime::get_named_arg::<Key>("gateway");
+    runtime::call_versioned_contract::<()>(
+        package_hash(gateway),
+        None,
+        "subscribe",
+        runtime_args! {
+            "entry_point" => String::from("on_notification"),
+        },
+    );
+}
+
+#[no_mangle]
+fn on_notification() {
+    let account = runtime::get_named_arg::<Key>("account");
+    let token_id = runtime::get_named_arg::<String>("token_id");
+    let action = runtime::get_named_arg::<String>("action");
+    let reason = runtime::get_named_arg::<u8>("reason");
+    let mut notifications: Vec<Notification> = get_key(NOTIFICATIONS).unwrap_or_default();
+    notifications.push((account, token_id, (action, reason)));
+    set_key(NOTIFICATIONS, notifications);
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let contract_name: String = runtime::get_named_arg("contract_name");
+    let (contract_hash, _) = storage::new_contract(
+        get_entry_points(),
+        None,
+        Some(format!("{}_contract_package_hash", contract_name)),
+        Some(format!("{}_access_token", contract_name)),
+    );
+    let package_hash = ContractPackageHash::new(
+        runtime::get_key(&format!("{}_contract_package_hash", contract_name))
+            .unwrap_or_revert()
+            .into_hash()
+            .unwrap_or_revert(),
+    );
+    runtime::put_key(
+        &format!("{}_contract_hash", contract_name),
+        contract_hash.into(),
+    );
+    runtime::put_key(
+        &format!("{}_contract_hash_wrapped", contract_name),
+        storage::new_uref(contract_hash).into(),
+    );
+    runtime::put_key(
+        &format!("{}_package_hash_wrapped", contract_name),
+        storage::new_uref(package_hash).into(),
+    );
+}
+
+fn package_hash(gateway: Key) -> ContractPackageHash {
+    ContractPackageHash::new(gateway.into_hash().unwrap_or_revert())
+}
+
+fn get_key<T: CLTyped + FromBytes>(name: &str) -> Option<T> {
+    runtime::get_key(name).map(|key| {
+        storage::read(key.try_into().unwrap_or_revert())
+            .unwrap_or_revert()
+            .unwrap_or_revert()
+    })
+}
+
+fn set_key<T: CLTyped + ToBytes>(name: &str, value: T) {
+    match runtime::get_key(name) {
+        Some(key) => storage::write(key.try_into().unwrap_or_revert(), value),
+        None => runtime::put_key(name, storage::new_uref(value).into()),
+    }
+}
+
+fn get_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+    entry_points.add_entry_point(EntryPoint::new(
+        "verify",
+        vec![
+            Parameter::new("gateway", Key::cl_type()),
+            Parameter::new("account", Key::cl_type()),
+            Parameter::new("index", CLType::Option(Box::new(U256::cl_type()))),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "verify_fresh",
+        vec![
+            Parameter::new("gateway", Key::cl_type()),
+            Parameter::new("account", Key::cl_type()),
+            Parameter::new("index", CLType::Option(Box::new(U256::cl_type()))),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "is_kyc_proved",
+        vec![
+            Parameter::new("gateway", Key::cl_type()),
+            Parameter::new("account", Key::cl_type()),
+            Parameter::new("index", CLType::Option(Box::new(U256::cl_type()))),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "is_kyc_proved_at_level",
+        vec![
+            Parameter::new("gateway", Key::cl_type()),
+            Parameter::new("account", Key::cl_type()),
+            Parameter::new("level", u8::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "is_eligible",
+        vec![
+            Parameter::new("gateway", Key::cl_type()),
+            Parameter::new("account", Key::cl_type()),
+            Parameter::new("policy_id", u64::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "verify_claim",
+        vec![
+            Parameter::new("gateway", Key::cl_type()),
+            Parameter::new("token_id", String::cl_type()),
+            Parameter::new("claim", String::cl_type()),
+            Parameter::new("value", String::cl_type()),
+            Parameter::new("salt", String::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "tokens_of",
+        vec![
+            Parameter::new("gateway", Key::cl_type()),
+            Parameter::new("owner", Key::cl_type()),
+            Parameter::new("offset", u64::cl_type()),
+            Parameter::new("limit", u64::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "tokens",
+        vec![
+            Parameter::new("gateway", Key::cl_type()),
+            Parameter::new("offset", u64::cl_type()),
+            Parameter::new("limit", u64::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "compromised_tokens",
+        vec![
+            Parameter::new("gateway", Key::cl_type()),
+            Parameter::new("gatekeeper", Key::cl_type()),
+            Parameter::new("offset", u64::cl_type()),
+            Parameter::new("limit", u64::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "subscribe",
+        vec![Parameter::new("gateway", Key::cl_type())],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points.add_entry_point(EntryPoint::new(
+        "on_notification",
+        vec![
+            Parameter::new("account", Key::cl_type()),
+            Parameter::new("token_id", String::cl_type()),
+            Parameter::new("action", String::cl_type()),
+            Parameter::new("reason", u8::cl_type()),
+        ],
+        <()>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    ));
+    entry_points
+}
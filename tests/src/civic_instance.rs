@@ -1,18 +1,37 @@
-use std::collections::BTreeMap;
+use std::{cell::Cell, collections::BTreeMap};
 
 use blake2::{
     digest::{Update, VariableOutput},
     VarBlake2b,
 };
 use casper_types::{
-    account::AccountHash, bytesrepr::ToBytes, runtime_args, CLTyped, Key, RuntimeArgs, U256,
+    account::AccountHash, bytesrepr::ToBytes, runtime_args, CLTyped, CLValue, Key, RuntimeArgs,
+    U256,
 };
 use test_env::{TestContract, TestEnv};
 
 pub type TokenId = String;
 pub type Meta = BTreeMap<String, String>;
+pub type Commitment = [u8; 32];
 
-pub struct CIVICInstance(TestContract);
+#[derive(Default, Clone)]
+pub struct MintOptions {
+    pub network: u64,
+    pub level: u8,
+    pub identity_commitment: Option<Commitment>,
+    pub claim_commitments: BTreeMap<String, Commitment>,
+    pub jurisdiction: Option<String>,
+    pub typed_claims: BTreeMap<String, CLValue>,
+}
+
+pub type Policy = (Key, bool, Vec<String>);
+pub type Quota = (u64, u32, u64);
+pub type NetworkInfo = (String, String, Key);
+pub type TokenPolicy = (u64, bool, bool);
+pub type TokenAction = (String, u8, (u64, Key));
+pub type Notification = (Key, TokenId, (String, u8));
+
+pub struct CIVICInstance(TestContract, Cell<u64>);
 
 impl CIVICInstance {
     pub fn new<T: Into<Key>>(
@@ -24,18 +43,33 @@ impl CIVICInstance {
         meta: Meta,
         admin: T,
     ) -> CIVICInstance {
-        CIVICInstance(TestContract::new(
-            env,
-            "civic-token.wasm",
-            contract_name,
-            sender,
-            runtime_args! {
-                "name" => name,
-                "symbol" => symbol,
-                "meta" => meta,
-                "admin" => admin.into()
-            },
-        ))
+        CIVICInstance(
+            TestContract::new(
+                env,
+                "civic-token.wasm",
+                contract_name,
+                sender,
+                runtime_args! {
+                    "name" => name,
+                    "symbol" => symbol,
+                    "meta" => meta,
+                    "admin" => admin.into()
+                },
+            ),
+            Cell::new(0),
+        )
+    }
+
+    /// Calls an entry point that has to revert, keeping the test running to check
+    /// the state it left.
+    pub fn call_expecting_failure(
+        &self,
+        sender: AccountHash,
+        entry_point: &str,
+        args: RuntimeArgs,
+    ) {
+        self.0
+            .call_contract_expecting_failure(sender, entry_point, args)
     }
 
     pub fn constructor(&self, sender: AccountHash, name: &str, symbol: &str, meta: Meta) {
@@ -56,17 +90,77 @@ impl CIVICInstance {
         token_id: Option<TokenId>,
         token_meta: Meta,
     ) {
+        self.mint_with_options(
+            sender,
+            recipient,
+            token_id,
+            token_meta,
+            MintOptions::default(),
+        )
+    }
+
+    pub fn mint_with_options<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        recipient: T,
+        token_id: Option<TokenId>,
+        token_meta: Meta,
+        options: MintOptions,
+    ) {
+        let identity_commitment = options
+            .identity_commitment
+            .unwrap_or_else(|| self.next_identity());
         self.0.call_contract(
             sender,
             "mint",
             runtime_args! {
                 "recipient" => recipient.into(),
                 "token_id" => token_id,
-                "token_meta" => token_meta
+                "token_meta" => token_meta,
+                "network" => options.network,
+                "level" => options.level,
+                "identity_commitment" => identity_commitment,
+                "claim_commitments" => options.claim_commitments,
+                "jurisdiction" => options.jurisdiction,
+                "typed_claims" => options.typed_claims
+            },
+        )
+    }
+
+    /// Mints passing only the arguments `mint` requires, leaving the others to
+    /// their defaults.
+    pub fn mint_with_required_args<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        recipient: T,
+        token_id: Option<TokenId>,
+        token_meta: Meta,
+        identity_commitment: Commitment,
+    ) {
+        self.0.call_contract(
+            sender,
+            "mint",
+            runtime_args! {
+                "recipient" => recipient.into(),
+                "token_id" => token_id,
+                "token_meta" => token_meta,
+                "identity_commitment" => identity_commitment
             },
         )
     }
 
+    /// Distinct identity commitment for mints that don't test identities.
+    fn next_identity(&self) -> Commitment {
+        let nonce = self.1.get();
+        self.1.set(nonce + 1);
+        let mut hasher = VarBlake2b::new(32).unwrap();
+        hasher.update(b"identity");
+        hasher.update(nonce.to_le_bytes());
+        let mut ret = [0u8; 32];
+        hasher.finalize_variable(|hash| ret.clone_from_slice(hash));
+        ret
+    }
+
     pub fn burn<T: Into<Key>>(&self, sender: AccountHash, owner: T, token_id: TokenId) {
         self.0.call_contract(
             sender,
@@ -165,68 +259,877 @@ impl CIVICInstance {
         )
     }
 
-    pub fn is_admin<T: Into<Key>>(&self, account: T) -> bool {
-        self.0
-            .query_dictionary::<()>("admins", key_to_str(&account.into()))
-            .is_some()
+    pub fn set_network_multiple_accounts(&self, sender: AccountHash, network: u64, allowed: bool) {
+        self.0.call_contract(
+            sender,
+            "set_network_multiple_accounts",
+            runtime_args! {
+                "network" => network,
+                "allowed" => allowed
+            },
+        )
     }
 
-    pub fn is_gatekeeper<T: Into<Key>>(&self, account: T) -> bool {
-        self.0
-            .query_dictionary::<()>("gatekeepers", key_to_str(&account.into()))
-            .is_some()
+    pub fn set_claim_commitment(
+        &self,
+        sender: AccountHash,
+        token_id: TokenId,
+        claim: &str,
+        commitment: Option<Commitment>,
+    ) {
+        self.0.call_contract(
+            sender,
+            "set_claim_commitment",
+            runtime_args! {
+                "token_id" => token_id,
+                "claim" => claim,
+                "commitment" => commitment
+            },
+        )
     }
 
-    pub fn get_token_by_index<T: Into<Key>>(&self, account: T, index: U256) -> Option<TokenId> {
-        self.0.query_dictionary(
-            "owned_tokens_by_index",
-            key_and_value_to_str(&account.into(), &index),
+    pub fn set_token_level(&self, sender: AccountHash, token_id: TokenId, level: u8) {
+        self.0.call_contract(
+            sender,
+            "set_token_level",
+            runtime_args! {
+                "token_id" => token_id,
+                "level" => level
+            },
         )
     }
 
-    pub fn balance_of<T: Into<Key>>(&self, account: T) -> U256 {
-        self.0
-            .query_dictionary("balances", key_to_str(&account.into()))
-            .unwrap_or_default()
+    pub fn set_gatekeeper_level_cap<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        network: u64,
+        gatekeeper: T,
+        max_level: Option<u8>,
+    ) {
+        self.0.call_contract(
+            sender,
+            "set_gatekeeper_level_cap",
+            runtime_args! {
+                "network" => network,
+                "gatekeeper" => gatekeeper.into(),
+                "max_level" => max_level
+            },
+        )
     }
 
-    pub fn owner_of(&self, token_id: TokenId) -> Option<Key> {
-        self.0.query_dictionary("owners", token_id)
+    pub fn block_accounts(&self, sender: AccountHash, accounts: Vec<Key>) {
+        self.0.call_contract(
+            sender,
+            "block_accounts",
+            runtime_args! {
+                "accounts" => accounts
+            },
+        )
     }
 
-    pub fn token_meta(&self, token_id: TokenId) -> Option<Meta> {
-        self.0.query_dictionary("metadata", token_id)
+    pub fn unblock_accounts(&self, sender: AccountHash, accounts: Vec<Key>) {
+        self.0.call_contract(
+            sender,
+            "unblock_accounts",
+            runtime_args! {
+                "accounts" => accounts
+            },
+        )
     }
 
-    pub fn name(&self) -> String {
-        self.0.query_named_key(String::from("name"))
+    pub fn grant_compliance_officer<T: Into<Key>>(&self, sender: AccountHash, officer: T) {
+        self.0.call_contract(
+            sender,
+            "grant_compliance_officer",
+            runtime_args! {
+                "officer" => officer.into()
+            },
+        )
     }
 
-    pub fn symbol(&self) -> String {
-        self.0.query_named_key(String::from("symbol"))
+    pub fn revoke_compliance_officer<T: Into<Key>>(&self, sender: AccountHash, officer: T) {
+        self.0.call_contract(
+            sender,
+            "revoke_compliance_officer",
+            runtime_args! {
+                "officer" => officer.into()
+            },
+        )
     }
 
-    pub fn total_supply(&self) -> U256 {
-        self.0.query_named_key(String::from("total_supply"))
+    pub fn set_token_jurisdiction(
+        &self,
+        sender: AccountHash,
+        token_id: TokenId,
+        jurisdiction: Option<String>,
+    ) {
+        self.0.call_contract(
+            sender,
+            "set_token_jurisdiction",
+            runtime_args! {
+                "token_id" => token_id,
+                "jurisdiction" => jurisdiction
+            },
+        )
     }
 
-    pub fn meta(&self) -> Meta {
-        self.0.query_named_key(String::from("meta"))
+    pub fn register_jurisdiction_policy(
+        &self,
+        sender: AccountHash,
+        allow: bool,
+        jurisdictions: Vec<String>,
+    ) {
+        self.0.call_contract(
+            sender,
+            "register_jurisdiction_policy",
+            runtime_args! {
+                "allow" => allow,
+                "jurisdictions" => jurisdictions
+            },
+        )
     }
-}
 
-pub fn key_to_str(key: &Key) -> String {
-    match key {
-        Key::Account(account) => account.to_string(),
-        Key::Hash(package) => hex::encode(package),
-        _ => panic!("Unexpected key type"),
+    pub fn update_jurisdiction_policy(
+        &self,
+        sender: AccountHash,
+        policy_id: u64,
+        allow: bool,
+        jurisdictions: Vec<String>,
+    ) {
+        self.0.call_contract(
+            sender,
+            "update_jurisdiction_policy",
+            runtime_args! {
+                "policy_id" => policy_id,
+                "allow" => allow,
+                "jurisdictions" => jurisdictions
+            },
+        )
     }
-}
 
-pub fn key_and_value_to_str<T: CLTyped + ToBytes>(key: &Key, value: &T) -> String {
-    let mut hasher = VarBlake2b::new(32).unwrap();
-    hasher.update(key.to_bytes().unwrap());
-    hasher.update(value.to_bytes().unwrap());
+    pub fn set_gatekeeper_quota<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        gatekeeper: T,
+        quota: Option<Quota>,
+    ) {
+        self.0.call_contract(
+            sender,
+            "set_gatekeeper_quota",
+            runtime_args! {
+                "gatekeeper" => gatekeeper.into(),
+                "quota" => quota
+            },
+        )
+    }
+
+    pub fn set_multisig_config(&self, sender: AccountHash, threshold: u32, proposal_lifetime: u64) {
+        self.0.call_contract(
+            sender,
+            "set_multisig_config",
+            runtime_args! {
+                "threshold" => threshold,
+                "proposal_lifetime" => proposal_lifetime
+            },
+        )
+    }
+
+    pub fn propose(&self, sender: AccountHash, entry_point: &str, args: RuntimeArgs) {
+        self.0.call_contract(
+            sender,
+            "propose",
+            runtime_args! {
+                "entry_point" => entry_point,
+                "args" => args.to_bytes().unwrap()
+            },
+        )
+    }
+
+    pub fn approve_proposal(&self, sender: AccountHash, proposal_id: u64) {
+        self.0.call_contract(
+            sender,
+            "approve_proposal",
+            runtime_args! {
+                "proposal_id" => proposal_id
+            },
+        )
+    }
+
+    pub fn emergency_revoke_gatekeeper<T: Into<Key>>(&self, sender: AccountHash, gatekeeper: T) {
+        self.0.call_contract(
+            sender,
+            "emergency_revoke_gatekeeper",
+            runtime_args! {
+                "gatekeeper" => gatekeeper.into()
+            },
+        )
+    }
+
+    pub fn emergency_revoke_admin<T: Into<Key>>(&self, sender: AccountHash, admin: T) {
+        self.0.call_contract(
+            sender,
+            "emergency_revoke_admin",
+            runtime_args! {
+                "admin" => admin.into()
+            },
+        )
+    }
+
+    pub fn set_role_change_delay(&self, sender: AccountHash, delay: u64) {
+        self.0.call_contract(
+            sender,
+            "set_role_change_delay",
+            runtime_args! {
+                "delay" => delay
+            },
+        )
+    }
+
+    pub fn execute_role_change(&self, sender: AccountHash, change_id: u64) {
+        self.0.call_contract(
+            sender,
+            "execute_role_change",
+            runtime_args! {
+                "change_id" => change_id
+            },
+        )
+    }
+
+    pub fn cancel_role_change(&self, sender: AccountHash, change_id: u64) {
+        self.0.call_contract(
+            sender,
+            "cancel_role_change",
+            runtime_args! {
+                "change_id" => change_id
+            },
+        )
+    }
+
+    pub fn register_network(&self, sender: AccountHash, network: u64, info: NetworkInfo) {
+        self.0.call_contract(
+            sender,
+            "register_network",
+            runtime_args! {
+                "network" => network,
+                "info" => info
+            },
+        )
+    }
+
+    pub fn set_network_token_policy(&self, sender: AccountHash, network: u64, policy: TokenPolicy) {
+        self.0.call_contract(
+            sender,
+            "set_network_token_policy",
+            runtime_args! {
+                "network" => network,
+                "policy" => policy
+            },
+        )
+    }
+
+    pub fn grant_network_gatekeeper<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        network: u64,
+        gatekeeper: T,
+    ) {
+        self.0.call_contract(
+            sender,
+            "grant_network_gatekeeper",
+            runtime_args! {
+                "network" => network,
+                "gatekeeper" => gatekeeper.into()
+            },
+        )
+    }
+
+    pub fn rotate_gatekeeper_key<T: Into<Key>>(&self, sender: AccountHash, new_key: T) {
+        self.0.call_contract(
+            sender,
+            "rotate_gatekeeper_key",
+            runtime_args! {
+                "new_key" => new_key.into()
+            },
+        )
+    }
+
+    pub fn confirm_gatekeeper_key<T: Into<Key>>(&self, sender: AccountHash, current_key: T) {
+        self.0.call_contract(
+            sender,
+            "confirm_gatekeeper_key",
+            runtime_args! {
+                "current_key" => current_key.into()
+            },
+        )
+    }
+
+    pub fn resign_gatekeeper(&self, sender: AccountHash) {
+        self.0
+            .call_contract(sender, "resign_gatekeeper", runtime_args! {})
+    }
+
+    pub fn mark_gatekeeper_compromised<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        gatekeeper: T,
+        since: Option<u64>,
+    ) {
+        self.0.call_contract(
+            sender,
+            "mark_gatekeeper_compromised",
+            runtime_args! {
+                "gatekeeper" => gatekeeper.into(),
+                "since" => since
+            },
+        )
+    }
+
+    pub fn suspend_gatekeeper<T: Into<Key>>(&self, sender: AccountHash, gatekeeper: T, until: u64) {
+        self.0.call_contract(
+            sender,
+            "suspend_gatekeeper",
+            runtime_args! {
+                "gatekeeper" => gatekeeper.into(),
+                "until" => until
+            },
+        )
+    }
+
+    pub fn lift_gatekeeper_suspension<T: Into<Key>>(&self, sender: AccountHash, gatekeeper: T) {
+        self.0.call_contract(
+            sender,
+            "lift_gatekeeper_suspension",
+            runtime_args! {
+                "gatekeeper" => gatekeeper.into()
+            },
+        )
+    }
+
+    pub fn set_gatekeeper_permissions<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        gatekeeper: T,
+        permissions: u8,
+    ) {
+        self.0.call_contract(
+            sender,
+            "set_gatekeeper_permissions",
+            runtime_args! {
+                "gatekeeper" => gatekeeper.into(),
+                "permissions" => permissions
+            },
+        )
+    }
+
+    pub fn set_typed_claim(
+        &self,
+        sender: AccountHash,
+        token_id: TokenId,
+        claim: String,
+        value: Option<CLValue>,
+    ) {
+        self.0.call_contract(
+            sender,
+            "set_typed_claim",
+            runtime_args! {
+                "token_id" => token_id,
+                "claim" => claim,
+                "value" => value
+            },
+        )
+    }
+
+    pub fn set_network_refresh_interval(&self, sender: AccountHash, network: u64, interval: u64) {
+        self.0.call_contract(
+            sender,
+            "set_network_refresh_interval",
+            runtime_args! {
+                "network" => network,
+                "interval" => interval
+            },
+        )
+    }
+
+    pub fn refresh(&self, sender: AccountHash, token_id: TokenId) {
+        self.0.call_contract(
+            sender,
+            "refresh",
+            runtime_args! {
+                "token_id" => token_id
+            },
+        )
+    }
+
+    pub fn freeze(&self, sender: AccountHash, token_id: TokenId, reason: u8) {
+        self.0.call_contract(
+            sender,
+            "freeze",
+            runtime_args! {
+                "token_id" => token_id,
+                "reason" => reason
+            },
+        )
+    }
+
+    pub fn unfreeze(&self, sender: AccountHash, token_id: TokenId) {
+        self.0.call_contract(
+            sender,
+            "unfreeze",
+            runtime_args! {
+                "token_id" => token_id
+            },
+        )
+    }
+
+    pub fn revoke(&self, sender: AccountHash, token_id: TokenId, reason: u8) {
+        self.0.call_contract(
+            sender,
+            "revoke",
+            runtime_args! {
+                "token_id" => token_id,
+                "reason" => reason
+            },
+        )
+    }
+
+    pub fn dispute(&self, sender: AccountHash, token_id: TokenId, evidence_hash: [u8; 32]) {
+        self.0.call_contract(
+            sender,
+            "dispute",
+            runtime_args! {
+                "token_id" => token_id,
+                "evidence_hash" => evidence_hash
+            },
+        )
+    }
+
+    pub fn resolve_dispute(&self, sender: AccountHash, token_id: TokenId, reinstate: bool) {
+        self.0.call_contract(
+            sender,
+            "resolve_dispute",
+            runtime_args! {
+                "token_id" => token_id,
+                "reinstate" => reinstate
+            },
+        )
+    }
+
+    pub fn link_key<T: Into<Key>>(&self, sender: AccountHash, key: T) {
+        self.0.call_contract(
+            sender,
+            "link_key",
+            runtime_args! {
+                "key" => key.into()
+            },
+        )
+    }
+
+    pub fn confirm_key_link<T: Into<Key>>(&self, sender: AccountHash, holder: T) {
+        self.0.call_contract(
+            sender,
+            "confirm_key_link",
+            runtime_args! {
+                "holder" => holder.into()
+            },
+        )
+    }
+
+    pub fn unlink_key<T: Into<Key>>(&self, sender: AccountHash, key: T) {
+        self.0.call_contract(
+            sender,
+            "unlink_key",
+            runtime_args! {
+                "key" => key.into()
+            },
+        )
+    }
+
+    pub fn subscribe(&self, sender: AccountHash, entry_point: &str) {
+        self.0.call_contract(
+            sender,
+            "subscribe",
+            runtime_args! {
+                "entry_point" => entry_point.to_string()
+            },
+        )
+    }
+
+    pub fn deliver_notifications<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        subscriber: T,
+        limit: u64,
+    ) {
+        self.0.call_contract(
+            sender,
+            "deliver_notifications",
+            runtime_args! {
+                "subscriber" => subscriber.into(),
+                "limit" => limit
+            },
+        )
+    }
+
+    pub fn trust_gateway(&self, sender: AccountHash, gateway: Key, networks: BTreeMap<u64, u64>) {
+        self.0.call_contract(
+            sender,
+            "trust_gateway",
+            runtime_args! {
+                "gateway" => gateway,
+                "networks" => networks
+            },
+        )
+    }
+
+    pub fn distrust_gateway(&self, sender: AccountHash, gateway: Key) {
+        self.0.call_contract(
+            sender,
+            "distrust_gateway",
+            runtime_args! {
+                "gateway" => gateway
+            },
+        )
+    }
+
+    pub fn sunset(&self, sender: AccountHash, successor: Key, forward_reads: bool) {
+        self.0.call_contract(
+            sender,
+            "sunset",
+            runtime_args! {
+                "successor" => successor,
+                "forward_reads" => forward_reads
+            },
+        )
+    }
+
+    pub fn set_gatekeeper_relayer<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        relayer: T,
+        allowed: bool,
+    ) {
+        self.0.call_contract(
+            sender,
+            "set_gatekeeper_relayer",
+            runtime_args! {
+                "relayer" => relayer.into(),
+                "allowed" => allowed
+            },
+        )
+    }
+
+    pub fn is_blocked<T: Into<Key>>(&self, account: T) -> bool {
+        self.0
+            .query_dictionary::<()>("blocklist", key_to_str(&account.into()))
+            .is_some()
+    }
+
+    pub fn is_admin<T: Into<Key>>(&self, account: T) -> bool {
+        self.0
+            .query_dictionary::<()>("admins", key_to_str(&account.into()))
+            .is_some()
+    }
+
+    pub fn is_gatekeeper<T: Into<Key>>(&self, account: T) -> bool {
+        self.0
+            .query_dictionary::<()>("gatekeepers", key_to_str(&account.into()))
+            .is_some()
+    }
+
+    pub fn get_token_by_index<T: Into<Key>>(&self, account: T, index: U256) -> Option<TokenId> {
+        self.0.query_dictionary(
+            "owned_tokens_by_index",
+            key_and_value_to_str(&account.into(), &index),
+        )
+    }
+
+    pub fn balance_of<T: Into<Key>>(&self, account: T) -> U256 {
+        self.0
+            .query_dictionary("balances", key_to_str(&account.into()))
+            .unwrap_or_default()
+    }
+
+    pub fn owner_of(&self, token_id: TokenId) -> Option<Key> {
+        self.0.query_dictionary("owners", token_id)
+    }
+
+    pub fn token_meta(&self, token_id: TokenId) -> Option<Meta> {
+        self.0.query_dictionary("metadata", token_id)
+    }
+
+    pub fn token_network(&self, token_id: TokenId) -> Option<u64> {
+        self.0.query_dictionary("token_networks", token_id)
+    }
+
+    pub fn token_identity(&self, token_id: TokenId) -> Option<Commitment> {
+        self.0.query_dictionary("token_identities", token_id)
+    }
+
+    pub fn identity_accounts(&self, network: u64, commitment: Commitment) -> Vec<Key> {
+        self.0
+            .query_dictionary("identity_accounts", values_to_str(&network, &commitment))
+            .unwrap_or_default()
+    }
+
+    pub fn claim_commitments(&self, token_id: TokenId) -> BTreeMap<String, Commitment> {
+        self.0
+            .query_dictionary("claim_commitments", token_id)
+            .unwrap_or_default()
+    }
+
+    pub fn token_level(&self, token_id: TokenId) -> Option<u8> {
+        self.0.query_dictionary("token_levels", token_id)
+    }
+
+    pub fn gatekeeper_level_cap<T: Into<Key>>(&self, network: u64, gatekeeper: T) -> Option<u8> {
+        self.0.query_dictionary(
+            "gatekeeper_level_caps",
+            values_to_str(&network, &gatekeeper.into()),
+        )
+    }
+
+    pub fn token_jurisdiction(&self, token_id: TokenId) -> Option<String> {
+        self.0.query_dictionary("token_jurisdictions", token_id)
+    }
+
+    pub fn jurisdiction_policy(&self, policy_id: u64) -> Option<Policy> {
+        self.0
+            .query_dictionary("jurisdiction_policies", policy_id.to_string())
+    }
+
+    pub fn jurisdiction_policy_count(&self) -> u64 {
+        self.0
+            .query_named_key(String::from("jurisdiction_policy_count"))
+    }
+
+    pub fn gatekeeper_quota<T: Into<Key>>(&self, gatekeeper: T) -> Option<Quota> {
+        self.0
+            .query_dictionary("gatekeeper_quotas", key_to_str(&gatekeeper.into()))
+    }
+
+    pub fn gatekeeper_live_tokens<T: Into<Key>>(&self, gatekeeper: T) -> u64 {
+        self.0
+            .query_dictionary("gatekeeper_live_tokens", key_to_str(&gatekeeper.into()))
+            .unwrap_or_default()
+    }
+
+    pub fn token_issuance(&self, token_id: TokenId) -> Option<(Key, u64)> {
+        self.0.query_dictionary("token_issuances", token_id)
+    }
+
+    pub fn proposal_approvals(&self, proposal_id: u64) -> Vec<Key> {
+        self.0
+            .query_dictionary("proposal_approvals", proposal_id.to_string())
+            .unwrap_or_default()
+    }
+
+    pub fn scheduled_role_change(&self, change_id: u64) -> Option<(String, Key, u64)> {
+        self.0
+            .query_dictionary("scheduled_role_changes", change_id.to_string())
+    }
+
+    pub fn role_change_proposer(&self, change_id: u64) -> Option<Key> {
+        self.0
+            .query_dictionary("role_change_proposers", change_id.to_string())
+    }
+
+    pub fn scheduled_role_change_delay(&self, change_id: u64) -> Option<u64> {
+        self.0
+            .query_dictionary("scheduled_role_change_delays", change_id.to_string())
+    }
+
+    pub fn role_change_delay(&self) -> u64 {
+        self.0.query_named_key(String::from("role_change_delay"))
+    }
+
+    pub fn gatekeeper_count(&self) -> u64 {
+        self.0.query_named_key(String::from("gatekeeper_count"))
+    }
+
+    pub fn gatekeeper_at(&self, index: u64) -> Option<Key> {
+        self.0
+            .query_dictionary("gatekeepers_by_index", index.to_string())
+    }
+
+    pub fn admin_count(&self) -> u64 {
+        self.0.query_named_key(String::from("admin_count"))
+    }
+
+    pub fn admin_at(&self, index: u64) -> Option<Key> {
+        self.0
+            .query_dictionary("admins_by_index", index.to_string())
+    }
+
+    pub fn token_count(&self) -> u64 {
+        self.0.query_named_key(String::from("token_count"))
+    }
+
+    pub fn token_at(&self, index: u64) -> Option<TokenId> {
+        self.0
+            .query_dictionary("tokens_by_index", index.to_string())
+    }
+
+    pub fn network_info(&self, network: u64) -> Option<NetworkInfo> {
+        self.0
+            .query_dictionary("network_infos", network.to_string())
+    }
+
+    pub fn network_token_policy(&self, network: u64) -> Option<TokenPolicy> {
+        self.0
+            .query_dictionary("network_token_policies", network.to_string())
+    }
+
+    pub fn is_network_gatekeeper<T: Into<Key>>(&self, network: u64, gatekeeper: T) -> bool {
+        self.0
+            .query_dictionary::<()>(
+                "network_gatekeepers",
+                values_to_str(&network, &gatekeeper.into()),
+            )
+            .is_some()
+    }
+
+    pub fn gatekeeper_key_identity<T: Into<Key>>(&self, key: T) -> Option<Key> {
+        self.0
+            .query_dictionary("gatekeeper_identities", key_to_str(&key.into()))
+    }
+
+    pub fn gatekeeper_compromised_since<T: Into<Key>>(&self, gatekeeper: T) -> Option<u64> {
+        self.0
+            .query_dictionary("compromised_gatekeepers", key_to_str(&gatekeeper.into()))
+    }
+
+    pub fn gatekeeper_suspended_until<T: Into<Key>>(&self, gatekeeper: T) -> Option<u64> {
+        self.0
+            .query_dictionary("gatekeeper_suspensions", key_to_str(&gatekeeper.into()))
+    }
+
+    pub fn gatekeeper_permissions<T: Into<Key>>(&self, gatekeeper: T) -> Option<u8> {
+        self.0
+            .query_dictionary("gatekeeper_permissions", key_to_str(&gatekeeper.into()))
+    }
+
+    pub fn typed_claims(&self, token_id: TokenId) -> BTreeMap<String, CLValue> {
+        self.0
+            .query_dictionary("typed_claims", token_id)
+            .unwrap_or_default()
+    }
+
+    pub fn token_stamps(&self, token_id: TokenId) -> Option<((u64, Key), (u64, Key))> {
+        self.0.query_dictionary("token_stamps", token_id)
+    }
+
+    pub fn network_refresh_interval(&self, network: u64) -> Option<u64> {
+        self.0
+            .query_dictionary("network_refresh_intervals", network.to_string())
+    }
+
+    pub fn last_refreshed(&self, token_id: TokenId) -> Option<u64> {
+        self.0.query_dictionary("token_refreshes", token_id)
+    }
+
+    pub fn token_actions(&self, token_id: TokenId) -> Vec<TokenAction> {
+        self.0
+            .query_dictionary("token_actions", token_id)
+            .unwrap_or_default()
+    }
+
+    pub fn token_dispute(&self, token_id: TokenId) -> Option<([u8; 32], (u64, Key))> {
+        self.0.query_dictionary("token_disputes", token_id)
+    }
+
+    pub fn linked_holder<T: Into<Key>>(&self, key: T) -> Option<Key> {
+        self.0
+            .query_dictionary("linked_key_holders", key_to_str(&key.into()))
+    }
+
+    pub fn linked_keys<T: Into<Key>>(&self, holder: T) -> Vec<Key> {
+        self.0
+            .query_dictionary("holder_linked_keys", key_to_str(&holder.into()))
+            .unwrap_or_default()
+    }
+
+    pub fn notification_count(&self) -> u64 {
+        self.0.query_named_key(String::from("notification_count"))
+    }
+
+    pub fn notification(&self, index: u64) -> Option<Notification> {
+        self.0.query_dictionary("notifications", index.to_string())
+    }
+
+    pub fn subscriber_cursor<T: Into<Key>>(&self, subscriber: T) -> Option<u64> {
+        self.0
+            .query_dictionary("subscriber_cursors", key_to_str(&subscriber.into()))
+    }
+
+    pub fn trusted_gateway_count(&self) -> u64 {
+        self.0
+            .query_named_key(String::from("trusted_gateway_count"))
+    }
+
+    pub fn trusted_gateway_networks(&self, gateway: Key) -> Option<BTreeMap<u64, u64>> {
+        self.0
+            .query_dictionary("trusted_gateway_networks", key_to_str(&gateway))
+    }
+
+    pub fn successor(&self) -> Key {
+        self.0.query_named_key(String::from("successor"))
+    }
+
+    pub fn is_gatekeeper_relayer<T: Into<Key>, U: Into<Key>>(
+        &self,
+        relayer: T,
+        gatekeeper: U,
+    ) -> bool {
+        self.0
+            .query_dictionary::<()>(
+                "gatekeeper_relayers",
+                values_to_str(&relayer.into(), &gatekeeper.into()),
+            )
+            .is_some()
+    }
+
+    pub fn package_hash(&self) -> Key {
+        Key::Hash(self.0.package_hash())
+    }
+
+    pub fn name(&self) -> String {
+        self.0.query_named_key(String::from("name"))
+    }
+
+    pub fn symbol(&self) -> String {
+        self.0.query_named_key(String::from("symbol"))
+    }
+
+    pub fn total_supply(&self) -> U256 {
+        self.0.query_named_key(String::from("total_supply"))
+    }
+
+    pub fn meta(&self) -> Meta {
+        self.0.query_named_key(String::from("meta"))
+    }
+}
+
+pub fn key_to_str(key: &Key) -> String {
+    match key {
+        Key::Account(account) => account.to_string(),
+        Key::Hash(package) => hex::encode(package),
+        _ => panic!("Unexpected key type"),
+    }
+}
+
+pub fn key_and_value_to_str<T: CLTyped + ToBytes>(key: &Key, value: &T) -> String {
+    let mut hasher = VarBlake2b::new(32).unwrap();
+    hasher.update(key.to_bytes().unwrap());
+    hasher.update(value.to_bytes().unwrap());
+    let mut ret = [0u8; 32];
+    hasher.finalize_variable(|hash| ret.clone_from_slice(hash));
+    hex::encode(ret)
+}
+
+pub fn values_to_str<A: ToBytes, B: ToBytes>(value_a: &A, value_b: &B) -> String {
+    let mut hasher = VarBlake2b::new(32).unwrap();
+    hasher.update(value_a.to_bytes().unwrap());
+    hasher.update(value_b.to_bytes().unwrap());
     let mut ret = [0u8; 32];
     hasher.finalize_variable(|hash| ret.clone_from_slice(hash));
     hex::encode(ret)
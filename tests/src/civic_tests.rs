@@ -1,11 +1,16 @@
-use casper_types::{account::AccountHash, Key, U256};
+use casper_types::{account::AccountHash, runtime_args, CLValue, Key, RuntimeArgs, U256};
+use civic_claims::{commit_claim, verify_claim};
 use std::collections::BTreeMap;
 use test_env::TestEnv;
 
-use crate::civic_instance::{CIVICInstance, Meta, TokenId};
+use crate::{
+    civic_instance::{key_to_str, CIVICInstance, Meta, MintOptions, NetworkInfo, TokenId},
+    consumer_instance::ConsumerInstance,
+};
 
 const NAME: &str = "CIVIC_KYC";
 const SYMBOL: &str = "CKYC";
+const MAX_PAGE_SIZE: u64 = 50;
 
 mod meta {
     use super::{BTreeMap, Meta};
@@ -21,6 +26,12 @@ mod meta {
         meta
     }
 
+    pub fn active_kyc() -> Meta {
+        let mut meta = BTreeMap::new();
+        meta.insert("status".to_string(), "active".to_string());
+        meta
+    }
+
     pub fn unverified_kyc() -> Meta {
         let mut meta = BTreeMap::new();
         meta.insert("status".to_string(), "unverified".to_string());
@@ -28,6 +39,26 @@ mod meta {
     }
 }
 
+mod reason {
+    pub const VERIFIED: u8 = 0;
+    pub const NO_TOKEN: u8 = 1;
+    pub const BLOCKED: u8 = 3;
+    pub const EXPIRED: u8 = 4;
+    pub const JURISDICTION_NOT_ALLOWED: u8 = 5;
+    pub const ISSUER_COMPROMISED: u8 = 6;
+    pub const NEEDS_REFRESH: u8 = 7;
+}
+
+mod permissions {
+    pub const MINT: u8 = 1;
+    pub const REFRESH: u8 = 1 << 1;
+    pub const FREEZE: u8 = 1 << 2;
+}
+
+fn namespaced_key(gatekeeper: AccountHash, name: &str) -> String {
+    format!("gk:{}:{}", key_to_str(&Key::Account(gatekeeper)), name)
+}
+
 fn deploy() -> (TestEnv, CIVICInstance, AccountHash) {
     let env = TestEnv::new();
     let owner = env.next_user();
@@ -43,6 +74,12 @@ fn deploy() -> (TestEnv, CIVICInstance, AccountHash) {
     (env, token, owner)
 }
 
+fn deploy_with_consumer() -> (TestEnv, CIVICInstance, ConsumerInstance, AccountHash) {
+    let (env, token, owner) = deploy();
+    let consumer = ConsumerInstance::new(&env, "consumer", owner);
+    (env, token, consumer, owner)
+}
+
 #[test]
 fn test_deploy() {
     let (_, token, owner) = deploy();
@@ -240,10 +277,13 @@ fn test_token_metadata_set_from_gatekeeper() {
     let bob = env.next_user();
     let token_id = TokenId::from("123456");
 
-    token.mint(owner, bob, Some(token_id.clone()), meta::unverified_kyc());
+    let mut token_meta = meta::verified_kyc();
+    token_meta.insert(String::from("expiry"), String::from("5555555"));
+
+    token.mint(owner, bob, Some(token_id.clone()), meta::verified_kyc());
     token.grant_gatekeeper(owner, ali);
-    token.set_token_meta(ali, token_id.clone(), meta::verified_kyc());
-    assert_eq!(token.token_meta(token_id).unwrap(), meta::verified_kyc());
+    token.set_token_meta(ali, token_id.clone(), token_meta.clone());
+    assert_eq!(token.token_meta(token_id).unwrap(), token_meta);
 }
 
 #[test]
@@ -254,8 +294,11 @@ fn test_token_metadata_set_from_non_gatekeeper() {
     let bob = env.next_user();
     let token_id = TokenId::from("123456");
 
-    token.mint(owner, bob, Some(token_id.clone()), meta::unverified_kyc());
-    token.set_token_meta(ali, token_id, meta::verified_kyc());
+    let mut token_meta = meta::verified_kyc();
+    token_meta.insert(String::from("expiry"), String::from("5555555"));
+
+    token.mint(owner, bob, Some(token_id.clone()), meta::verified_kyc());
+    token.set_token_meta(ali, token_id, token_meta);
 }
 
 #[test]
@@ -265,7 +308,7 @@ fn test_token_metadata_update_from_gatekeeper() {
     let bob = env.next_user();
     let token_id = TokenId::from("123456");
 
-    token.mint(owner, bob, Some(token_id.clone()), meta::unverified_kyc());
+    token.mint(owner, bob, Some(token_id.clone()), meta::verified_kyc());
     token.grant_gatekeeper(owner, ali);
     token.set_token_meta(ali, token_id.clone(), meta::verified_kyc());
     token.update_token_meta(
@@ -287,7 +330,7 @@ fn test_token_metadata_update_from_non_gatekeeper() {
     let bob = env.next_user();
     let token_id = TokenId::from("123456");
 
-    token.mint(owner, bob, Some(token_id.clone()), meta::unverified_kyc());
+    token.mint(owner, bob, Some(token_id.clone()), meta::verified_kyc());
     token.grant_gatekeeper(owner, ali);
     token.set_token_meta(ali, token_id.clone(), meta::verified_kyc());
     token.revoke_gatekeeper(owner, ali);
@@ -298,3 +341,2127 @@ fn test_token_metadata_update_from_non_gatekeeper() {
         String::from("5555555"),
     );
 }
+
+#[test]
+fn test_mint_with_identity_commitment() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = TokenId::from("123456");
+    let options = MintOptions {
+        identity_commitment: Some([1u8; 32]),
+        ..Default::default()
+    };
+
+    token.mint_with_options(
+        owner,
+        ali,
+        Some(token_id.clone()),
+        meta::verified_kyc(),
+        options,
+    );
+    assert_eq!(token.token_network(token_id.clone()), Some(0));
+    assert_eq!(token.token_identity(token_id.clone()), Some([1u8; 32]));
+    assert_eq!(
+        token.identity_accounts(0, [1u8; 32]),
+        vec![Key::Account(ali)]
+    );
+
+    token.burn(owner, ali, token_id.clone());
+    assert_eq!(token.token_identity(token_id), None);
+    assert!(token.identity_accounts(0, [1u8; 32]).is_empty());
+}
+
+#[test]
+fn test_mint_with_required_args_only() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = TokenId::from("123456");
+
+    token.mint_with_required_args(
+        owner,
+        ali,
+        Some(token_id.clone()),
+        meta::active_kyc(),
+        [7u8; 32],
+    );
+    assert_eq!(token.owner_of(token_id.clone()), Some(Key::Account(ali)));
+    assert_eq!(token.token_identity(token_id.clone()), Some([7u8; 32]));
+    assert_eq!(token.token_network(token_id.clone()), Some(0));
+    assert_eq!(token.token_level(token_id.clone()), Some(0));
+    assert_eq!(token.token_jurisdiction(token_id.clone()), None);
+    assert_eq!(token.claim_commitments(token_id.clone()), BTreeMap::new());
+    assert_eq!(token.typed_claims(token_id), BTreeMap::new());
+}
+
+#[test]
+fn test_mint_always_binds_identity() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = TokenId::from("123456");
+
+    token.mint(owner, ali, Some(token_id.clone()), meta::verified_kyc());
+    let commitment = token.token_identity(token_id).unwrap();
+    assert_eq!(
+        token.identity_accounts(0, commitment),
+        vec![Key::Account(ali)]
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_mint_second_account_for_identity() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let options = MintOptions {
+        identity_commitment: Some([1u8; 32]),
+        ..Default::default()
+    };
+
+    token.mint_with_options(owner, ali, None, meta::verified_kyc(), options.clone());
+    token.mint_with_options(owner, bob, None, meta::verified_kyc(), options); // panic here
+}
+
+#[test]
+fn test_mint_second_account_for_identity_when_allowed() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let options = MintOptions {
+        identity_commitment: Some([1u8; 32]),
+        ..Default::default()
+    };
+
+    token.set_network_multiple_accounts(owner, 0, true);
+    token.mint_with_options(owner, ali, None, meta::verified_kyc(), options.clone());
+    token.mint_with_options(owner, bob, None, meta::verified_kyc(), options);
+    assert_eq!(
+        token.identity_accounts(0, [1u8; 32]),
+        vec![Key::Account(ali), Key::Account(bob)]
+    );
+}
+
+#[test]
+fn test_claim_commitment_helper() {
+    let commitment = commit_claim("country", "CH", "salt");
+    assert!(verify_claim(&commitment, "country", "CH", "salt"));
+    assert!(!verify_claim(&commitment, "country", "DE", "salt"));
+    assert!(!verify_claim(&commitment, "country", "CH", "other salt"));
+    assert_ne!(commitment, commit_claim("countryC", "H", "salt"));
+}
+
+#[test]
+fn test_mint_with_claim_commitments() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = TokenId::from("123456");
+    let mut claim_commitments = BTreeMap::new();
+    claim_commitments.insert("country".to_string(), commit_claim("country", "CH", "salt"));
+    let options = MintOptions {
+        claim_commitments: claim_commitments.clone(),
+        ..Default::default()
+    };
+
+    token.mint_with_options(
+        owner,
+        ali,
+        Some(token_id.clone()),
+        meta::verified_kyc(),
+        options,
+    );
+    assert_eq!(token.claim_commitments(token_id.clone()), claim_commitments);
+
+    let date_of_birth = commit_claim("date_of_birth", "1990-01-01", "salt");
+    token.set_claim_commitment(
+        owner,
+        token_id.clone(),
+        "date_of_birth",
+        Some(date_of_birth),
+    );
+    token.set_claim_commitment(owner, token_id.clone(), "country", None);
+    let mut expected_result = BTreeMap::new();
+    expected_result.insert("date_of_birth".to_string(), date_of_birth);
+    assert_eq!(token.claim_commitments(token_id), expected_result);
+}
+
+#[test]
+fn test_verify_claim_through_consumer() {
+    let (env, token, consumer, owner) = deploy_with_consumer();
+    let ali = env.next_user();
+    let token_id = TokenId::from("123456");
+    let mut claim_commitments = BTreeMap::new();
+    claim_commitments.insert("country".to_string(), commit_claim("country", "CH", "salt"));
+    let options = MintOptions {
+        claim_commitments,
+        ..Default::default()
+    };
+
+    token.mint_with_options(
+        owner,
+        ali,
+        Some(token_id.clone()),
+        meta::active_kyc(),
+        options,
+    );
+    consumer.verify_claim(
+        owner,
+        token.package_hash(),
+        token_id.clone(),
+        "country",
+        "CH",
+        "salt",
+    );
+    assert!(consumer.last_claim_verified());
+
+    consumer.verify_claim(
+        owner,
+        token.package_hash(),
+        token_id.clone(),
+        "country",
+        "DE",
+        "salt",
+    );
+    assert!(!consumer.last_claim_verified());
+    consumer.verify_claim(
+        owner,
+        token.package_hash(),
+        token_id,
+        "date_of_birth",
+        "1990-01-01",
+        "salt",
+    );
+    assert!(!consumer.last_claim_verified());
+}
+
+#[test]
+#[should_panic]
+fn test_claim_commitment_set_from_non_gatekeeper() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = TokenId::from("123456");
+
+    token.mint(owner, ali, Some(token_id.clone()), meta::verified_kyc());
+    token.set_claim_commitment(
+        ali,
+        token_id,
+        "country",
+        Some(commit_claim("country", "CH", "salt")),
+    );
+}
+
+#[test]
+fn test_mint_and_upgrade_level() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = TokenId::from("123456");
+    let options = MintOptions {
+        level: 1,
+        ..Default::default()
+    };
+
+    token.grant_gatekeeper(owner, ali);
+    token.set_gatekeeper_level_cap(owner, 0, ali, Some(2));
+    assert_eq!(token.gatekeeper_level_cap(0, ali), Some(2));
+
+    token.mint_with_options(
+        ali,
+        bob,
+        Some(token_id.clone()),
+        meta::verified_kyc(),
+        options,
+    );
+    assert_eq!(token.token_level(token_id.clone()), Some(1));
+
+    token.set_token_level(ali, token_id.clone(), 2);
+    assert_eq!(token.token_level(token_id), Some(2));
+}
+
+#[test]
+fn test_kyc_proved_at_level() {
+    let (env, token, consumer, owner) = deploy_with_consumer();
+    let ali = env.next_user();
+    let options = MintOptions {
+        level: 2,
+        ..Default::default()
+    };
+
+    token.mint_with_options(owner, ali, None, meta::active_kyc(), options);
+    consumer.is_kyc_proved_at_level(owner, token.package_hash(), ali, 1);
+    assert!(consumer.last_kyc_proved());
+    consumer.is_kyc_proved_at_level(owner, token.package_hash(), ali, 2);
+    assert!(consumer.last_kyc_proved());
+    consumer.is_kyc_proved_at_level(owner, token.package_hash(), ali, 3);
+    assert!(!consumer.last_kyc_proved());
+}
+
+#[test]
+#[should_panic]
+fn test_mint_above_level_cap() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let options = MintOptions {
+        level: 2,
+        ..Default::default()
+    };
+
+    token.grant_gatekeeper(owner, ali);
+    token.set_gatekeeper_level_cap(owner, 0, ali, Some(1));
+    token.mint_with_options(ali, bob, None, meta::verified_kyc(), options); // panic here
+}
+
+#[test]
+#[should_panic]
+fn test_upgrade_above_level_cap() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = TokenId::from("123456");
+
+    token.grant_gatekeeper(owner, ali);
+    token.set_gatekeeper_level_cap(owner, 0, ali, Some(1));
+    token.mint(ali, bob, Some(token_id.clone()), meta::verified_kyc());
+    token.set_token_level(ali, token_id, 2); // panic here
+}
+
+#[test]
+fn test_block_accounts_from_admin() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.block_accounts(owner, vec![Key::Account(ali), Key::Account(bob)]);
+    assert!(token.is_blocked(ali));
+    assert!(token.is_blocked(bob));
+
+    token.unblock_accounts(owner, vec![Key::Account(ali)]);
+    assert!(!token.is_blocked(ali));
+    assert!(token.is_blocked(bob));
+}
+
+#[test]
+fn test_block_accounts_from_compliance_officer() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.grant_compliance_officer(owner, ali);
+    token.block_accounts(ali, vec![Key::Account(bob)]);
+    assert!(token.is_blocked(bob));
+}
+
+#[test]
+#[should_panic]
+fn test_block_accounts_from_revoked_compliance_officer() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.grant_compliance_officer(owner, ali);
+    token.revoke_compliance_officer(owner, ali);
+    token.block_accounts(ali, vec![Key::Account(bob)]); // panic here
+}
+
+#[test]
+fn test_blocked_account_fails_verification() {
+    let (env, token, consumer, owner) = deploy_with_consumer();
+    let ali = env.next_user();
+
+    token.mint(owner, ali, None, meta::active_kyc());
+    consumer.verify(owner, token.package_hash(), ali);
+    assert_eq!(consumer.last_verification(), (true, reason::VERIFIED));
+
+    token.block_accounts(owner, vec![Key::Account(ali)]);
+    consumer.verify(owner, token.package_hash(), ali);
+    assert_eq!(consumer.last_verification(), (false, reason::BLOCKED));
+    consumer.is_kyc_proved(owner, token.package_hash(), ali);
+    assert!(!consumer.last_kyc_proved());
+}
+
+#[test]
+fn test_mint_and_update_jurisdiction() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = TokenId::from("123456");
+    let options = MintOptions {
+        jurisdiction: Some("CH".to_string()),
+        ..Default::default()
+    };
+
+    token.grant_gatekeeper(owner, ali);
+    token.mint_with_options(
+        ali,
+        bob,
+        Some(token_id.clone()),
+        meta::verified_kyc(),
+        options,
+    );
+    assert_eq!(
+        token.token_jurisdiction(token_id.clone()),
+        Some("CH".to_string())
+    );
+
+    token.set_token_jurisdiction(ali, token_id.clone(), Some("DE".to_string()));
+    assert_eq!(
+        token.token_jurisdiction(token_id.clone()),
+        Some("DE".to_string())
+    );
+
+    token.burn(ali, bob, token_id.clone());
+    assert_eq!(token.token_jurisdiction(token_id), None);
+}
+
+#[test]
+fn test_register_and_update_jurisdiction_policy() {
+    let (env, token, _) = deploy();
+    let ali = env.next_user();
+    let jurisdictions = vec!["US".to_string(), "KP".to_string()];
+
+    token.register_jurisdiction_policy(ali, false, jurisdictions.clone());
+    assert_eq!(token.jurisdiction_policy_count(), 1);
+    assert_eq!(
+        token.jurisdiction_policy(0),
+        Some((Key::Account(ali), false, jurisdictions))
+    );
+
+    token.update_jurisdiction_policy(ali, 0, true, vec!["CH".to_string()]);
+    assert_eq!(
+        token.jurisdiction_policy(0),
+        Some((Key::Account(ali), true, vec!["CH".to_string()]))
+    );
+}
+
+#[test]
+fn test_eligibility_under_jurisdiction_policies() {
+    let (env, token, consumer, owner) = deploy_with_consumer();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let carl = env.next_user();
+    let swiss = MintOptions {
+        jurisdiction: Some("CH".to_string()),
+        ..Default::default()
+    };
+    let mut expired_kyc = meta::active_kyc();
+    expired_kyc.insert(String::from("expiry"), String::from("0"));
+
+    token.mint_with_options(owner, ali, None, meta::active_kyc(), swiss.clone());
+    token.mint(owner, bob, None, meta::active_kyc());
+    token.mint_with_options(owner, carl, None, expired_kyc, swiss);
+    token.register_jurisdiction_policy(owner, true, vec!["CH".to_string()]);
+    token.register_jurisdiction_policy(owner, false, vec!["CH".to_string()]);
+
+    consumer.is_eligible(owner, token.package_hash(), ali, 0);
+    assert_eq!(consumer.last_eligibility(), (true, reason::VERIFIED));
+    consumer.is_eligible(owner, token.package_hash(), ali, 1);
+    assert_eq!(
+        consumer.last_eligibility(),
+        (false, reason::JURISDICTION_NOT_ALLOWED)
+    );
+
+    consumer.is_eligible(owner, token.package_hash(), bob, 0);
+    assert_eq!(
+        consumer.last_eligibility(),
+        (false, reason::JURISDICTION_NOT_ALLOWED)
+    );
+    consumer.is_eligible(owner, token.package_hash(), bob, 1);
+    assert_eq!(
+        consumer.last_eligibility(),
+        (false, reason::JURISDICTION_NOT_ALLOWED)
+    );
+
+    consumer.is_eligible(owner, token.package_hash(), carl, 0);
+    assert_eq!(consumer.last_eligibility(), (false, reason::EXPIRED));
+}
+
+#[test]
+#[should_panic]
+fn test_update_jurisdiction_policy_from_non_owner() {
+    let (env, token, _) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.register_jurisdiction_policy(ali, true, vec!["CH".to_string()]);
+    token.update_jurisdiction_policy(bob, 0, false, vec![]); // panic here
+}
+
+#[test]
+fn test_live_token_quota() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = TokenId::from("123456");
+
+    token.grant_gatekeeper(owner, ali);
+    token.set_gatekeeper_quota(owner, ali, Some((1, 0, 0)));
+    assert_eq!(token.gatekeeper_quota(ali), Some((1, 0, 0)));
+
+    token.mint(ali, bob, Some(token_id.clone()), meta::verified_kyc());
+    assert_eq!(token.gatekeeper_live_tokens(ali), 1);
+    assert_eq!(
+        token
+            .token_issuance(token_id.clone())
+            .map(|(issuer, _)| issuer),
+        Some(Key::Account(ali))
+    );
+
+    token.burn(ali, bob, token_id.clone());
+    assert_eq!(token.gatekeeper_live_tokens(ali), 0);
+    assert_eq!(token.token_issuance(token_id), None);
+
+    token.mint(ali, bob, None, meta::verified_kyc());
+    assert_eq!(token.gatekeeper_live_tokens(ali), 1);
+}
+
+#[test]
+#[should_panic]
+fn test_mint_above_live_token_quota() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.grant_gatekeeper(owner, ali);
+    token.set_gatekeeper_quota(owner, ali, Some((1, 0, 0)));
+    token.mint(ali, bob, None, meta::verified_kyc());
+    token.mint(ali, bob, None, meta::verified_kyc()); // panic here
+}
+
+#[test]
+#[should_panic]
+fn test_mint_above_rate_limit() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.grant_gatekeeper(owner, ali);
+    token.set_gatekeeper_quota(owner, ali, Some((0, 1, 3_600_000)));
+    token.mint(ali, bob, None, meta::verified_kyc());
+    token.mint(ali, bob, None, meta::verified_kyc()); // panic here
+}
+
+#[test]
+fn test_grant_admin_by_proposal() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.grant_admin(owner, ali);
+    token.set_multisig_config(owner, 2, 3_600_000);
+
+    token.propose(
+        owner,
+        "grant_admin",
+        runtime_args! { "admin" => Key::Account(bob) },
+    );
+    assert_eq!(token.proposal_approvals(0), vec![Key::Account(owner)]);
+    assert!(!token.is_admin(bob));
+
+    token.approve_proposal(ali, 0);
+    assert!(token.is_admin(bob));
+    assert!(token.proposal_approvals(0).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_grant_admin_directly_above_threshold() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.grant_admin(owner, ali);
+    token.set_multisig_config(owner, 2, 3_600_000);
+    token.grant_admin(owner, bob); // panic here
+}
+
+#[test]
+#[should_panic]
+fn test_multisig_threshold_above_admin_count() {
+    let (_, token, owner) = deploy();
+    token.set_multisig_config(owner, 2, 3_600_000); // panic here
+}
+
+#[test]
+#[should_panic]
+fn test_multisig_threshold_zero() {
+    let (_, token, owner) = deploy();
+    token.set_multisig_config(owner, 0, 3_600_000); // panic here
+}
+
+#[test]
+#[should_panic]
+fn test_revoke_admin_below_threshold() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+
+    token.grant_admin(owner, ali);
+    token.set_multisig_config(owner, 2, 3_600_000);
+    token.propose(
+        owner,
+        "revoke_admin",
+        runtime_args! { "admin" => Key::Account(ali) },
+    );
+    token.approve_proposal(ali, 0); // panic here
+}
+
+#[test]
+#[should_panic]
+fn test_approve_proposal_twice() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.grant_admin(owner, ali);
+    token.set_multisig_config(owner, 2, 3_600_000);
+    token.propose(
+        owner,
+        "grant_admin",
+        runtime_args! { "admin" => Key::Account(bob) },
+    );
+    token.approve_proposal(owner, 0); // panic here
+}
+
+#[test]
+fn test_schedule_and_cancel_role_change() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.grant_admin(owner, ali);
+    token.set_role_change_delay(owner, 3_600_000);
+
+    token.grant_gatekeeper(owner, bob);
+    assert!(!token.is_gatekeeper(bob));
+    assert_eq!(
+        token.scheduled_role_change(0),
+        Some(("grant_gatekeeper".to_string(), Key::Account(bob), 3_600_000))
+    );
+    assert_eq!(token.role_change_proposer(0), Some(Key::Account(owner)));
+
+    token.cancel_role_change(ali, 0);
+    assert_eq!(token.scheduled_role_change(0), None);
+}
+
+#[test]
+#[should_panic]
+fn test_cancel_own_role_change() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+
+    token.set_role_change_delay(owner, 3_600_000);
+    token.grant_gatekeeper(owner, ali);
+    token.cancel_role_change(owner, 0); // panic here
+}
+
+#[test]
+fn test_role_change_delay_change_is_scheduled() {
+    let (_, token, owner) = deploy();
+
+    token.set_role_change_delay(owner, 3_600_000);
+    token.set_role_change_delay(owner, 0);
+    assert_eq!(token.role_change_delay(), 3_600_000);
+    assert_eq!(
+        token.scheduled_role_change(0),
+        Some((
+            "set_role_change_delay".to_string(),
+            Key::Account(owner),
+            3_600_000
+        ))
+    );
+    assert_eq!(token.scheduled_role_change_delay(0), Some(0));
+}
+
+#[test]
+#[should_panic]
+fn test_execute_role_change_before_delay() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+
+    token.set_role_change_delay(owner, 3_600_000);
+    token.grant_gatekeeper(owner, ali);
+    token.execute_role_change(owner, 0); // panic here
+}
+
+#[test]
+fn test_emergency_revoke_gatekeeper() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+
+    token.grant_gatekeeper(owner, ali);
+    token.set_role_change_delay(owner, 3_600_000);
+
+    token.revoke_gatekeeper(owner, ali);
+    assert!(token.is_gatekeeper(ali));
+
+    token.emergency_revoke_gatekeeper(owner, ali);
+    assert!(!token.is_gatekeeper(ali));
+}
+
+#[test]
+#[should_panic]
+fn test_emergency_revoke_admin_by_non_admin() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+
+    token.emergency_revoke_admin(ali, owner); // panic here
+}
+
+#[test]
+fn test_emergency_revoke_admin_by_proposal() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.grant_admin(owner, ali);
+    token.grant_admin(owner, bob);
+    token.set_multisig_config(owner, 2, 3_600_000);
+    token.propose(
+        owner,
+        "set_role_change_delay",
+        runtime_args! { "delay" => 3_600_000u64 },
+    );
+    token.approve_proposal(ali, 0);
+
+    token.propose(
+        owner,
+        "emergency_revoke_admin",
+        runtime_args! { "admin" => Key::Account(bob) },
+    );
+    assert!(token.is_admin(bob));
+    token.approve_proposal(ali, 1);
+    assert!(!token.is_admin(bob));
+}
+
+#[test]
+#[should_panic]
+fn test_emergency_revoke_admin_directly_above_threshold() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.grant_admin(owner, ali);
+    token.grant_admin(owner, bob);
+    token.set_multisig_config(owner, 2, 3_600_000);
+    token.emergency_revoke_admin(owner, bob); // panic here
+}
+
+#[test]
+#[should_panic]
+fn test_emergency_revoke_last_admin() {
+    let (_, token, owner) = deploy();
+
+    token.emergency_revoke_admin(owner, owner); // panic here
+}
+
+#[test]
+fn test_enumerate_gatekeepers() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.grant_gatekeeper(owner, ali);
+    token.grant_gatekeeper(owner, bob);
+    assert_eq!(token.gatekeeper_count(), 3);
+    assert_eq!(token.gatekeeper_at(0), Some(Key::Account(owner)));
+    assert_eq!(token.gatekeeper_at(1), Some(Key::Account(ali)));
+    assert_eq!(token.gatekeeper_at(2), Some(Key::Account(bob)));
+
+    token.revoke_gatekeeper(owner, owner);
+    assert_eq!(token.gatekeeper_count(), 2);
+    assert_eq!(token.gatekeeper_at(0), Some(Key::Account(bob)));
+    assert_eq!(token.gatekeeper_at(1), Some(Key::Account(ali)));
+    assert_eq!(token.gatekeeper_at(2), None);
+}
+
+#[test]
+fn test_enumerate_admins() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+
+    assert_eq!(token.admin_count(), 1);
+    token.grant_admin(owner, ali);
+    assert_eq!(token.admin_count(), 2);
+    assert_eq!(token.admin_at(1), Some(Key::Account(ali)));
+
+    token.revoke_admin(owner, ali);
+    assert_eq!(token.admin_count(), 1);
+    assert_eq!(token.admin_at(0), Some(Key::Account(owner)));
+    assert_eq!(token.admin_at(1), None);
+}
+
+#[test]
+fn test_global_token_index() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_a = TokenId::from("123456");
+    let token_b = TokenId::from("654321");
+
+    token.mint(owner, ali, Some(token_a.clone()), meta::verified_kyc());
+    token.mint(owner, bob, Some(token_b.clone()), meta::verified_kyc());
+    assert_eq!(token.token_count(), 2);
+    assert_eq!(token.token_at(0), Some(token_a.clone()));
+    assert_eq!(token.token_at(1), Some(token_b.clone()));
+
+    token.burn(owner, ali, token_a);
+    assert_eq!(token.token_count(), 1);
+    assert_eq!(token.token_at(0), Some(token_b));
+    assert_eq!(token.token_at(1), None);
+}
+
+#[test]
+fn test_token_listing_pages() {
+    let (env, token, consumer, owner) = deploy_with_consumer();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let active = || String::from("active");
+    let options = MintOptions {
+        network: 1,
+        ..Default::default()
+    };
+
+    token.register_network(owner, 1, network_info("partner", owner));
+    token.set_network_token_policy(owner, 1, (1_000, true, false));
+    token.mint(owner, ali, Some(TokenId::from("1")), meta::active_kyc());
+    token.mint(owner, bob, Some(TokenId::from("2")), meta::active_kyc());
+    token.mint_with_options(
+        owner,
+        bob,
+        Some(TokenId::from("3")),
+        meta::active_kyc(),
+        options,
+    );
+    token.freeze(owner, TokenId::from("2"), 0);
+
+    consumer.tokens(owner, token.package_hash(), 0, 2);
+    assert_eq!(
+        consumer.last_tokens(),
+        vec![
+            (TokenId::from("1"), (active(), None, 0)),
+            (TokenId::from("2"), (String::from("frozen"), None, 0)),
+        ]
+    );
+    consumer.tokens(owner, token.package_hash(), 2, 10);
+    assert_eq!(
+        consumer.last_tokens(),
+        vec![(TokenId::from("3"), (active(), Some(1_000), 1))]
+    );
+    consumer.tokens(owner, token.package_hash(), 3, 10);
+    assert_eq!(consumer.last_tokens(), vec![]);
+
+    consumer.tokens_of(owner, token.package_hash(), bob, 1, 10);
+    assert_eq!(
+        consumer.last_tokens(),
+        vec![(TokenId::from("3"), (active(), Some(1_000), 1))]
+    );
+    consumer.tokens_of(owner, token.package_hash(), bob, 0, 1);
+    assert_eq!(
+        consumer.last_tokens(),
+        vec![(TokenId::from("2"), (String::from("frozen"), None, 0))]
+    );
+    consumer.tokens_of(owner, token.package_hash(), ali, 1, 10);
+    assert_eq!(consumer.last_tokens(), vec![]);
+}
+
+#[test]
+fn test_token_listing_page_size_cap() {
+    let (env, token, consumer, owner) = deploy_with_consumer();
+    let ali = env.next_user();
+
+    for index in 0..=MAX_PAGE_SIZE {
+        token.mint(owner, ali, Some(index.to_string()), meta::active_kyc());
+    }
+
+    consumer.tokens(owner, token.package_hash(), 0, MAX_PAGE_SIZE + 1);
+    assert_eq!(consumer.last_tokens().len() as u64, MAX_PAGE_SIZE);
+    consumer.tokens_of(owner, token.package_hash(), ali, 0, u64::MAX);
+    assert_eq!(consumer.last_tokens().len() as u64, MAX_PAGE_SIZE);
+    consumer.tokens_of(owner, token.package_hash(), ali, MAX_PAGE_SIZE, u64::MAX);
+    assert_eq!(
+        consumer.last_tokens(),
+        vec![(MAX_PAGE_SIZE.to_string(), (String::from("active"), None, 0))]
+    );
+}
+
+fn network_info(name: &str, authority: AccountHash) -> NetworkInfo {
+    (name.to_string(), String::new(), Key::Account(authority))
+}
+
+#[test]
+fn test_default_network() {
+    let (_, token, owner) = deploy();
+    assert_eq!(token.network_info(0), Some(network_info("default", owner)));
+}
+
+#[test]
+fn test_network_authority_manages_gatekeepers() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let carl = env.next_user();
+    let token_id = TokenId::from("123456");
+    let options = MintOptions {
+        network: 1,
+        ..Default::default()
+    };
+
+    token.register_network(owner, 1, network_info("partner", ali));
+    token.grant_network_gatekeeper(ali, 1, bob);
+    assert!(token.is_network_gatekeeper(1, bob));
+    assert!(!token.is_gatekeeper(bob));
+
+    token.set_network_token_policy(ali, 1, (1_000, true, false));
+    assert_eq!(token.network_token_policy(1), Some((1_000, true, false)));
+
+    token.mint_with_options(
+        bob,
+        carl,
+        Some(token_id.clone()),
+        meta::verified_kyc(),
+        options,
+    );
+    assert_eq!(token.token_network(token_id.clone()), Some(1));
+    assert_eq!(
+        token
+            .token_meta(token_id)
+            .unwrap()
+            .get("expiry")
+            .map(String::as_str),
+        Some("1000")
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_network_gatekeeper_mints_in_other_network() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.register_network(owner, 1, network_info("partner", owner));
+    token.grant_network_gatekeeper(owner, 1, ali);
+    token.mint(ali, bob, None, meta::verified_kyc()); // panic here
+}
+
+#[test]
+#[should_panic]
+fn test_set_network_token_policy_from_non_authority() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.register_network(owner, 1, network_info("partner", ali));
+    token.set_network_token_policy(bob, 1, (0, false, false)); // panic here
+}
+
+#[test]
+#[should_panic]
+fn test_mint_in_unregistered_network() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let options = MintOptions {
+        network: 7,
+        ..Default::default()
+    };
+
+    token.mint_with_options(owner, ali, None, meta::verified_kyc(), options); // panic here
+}
+
+#[test]
+fn test_rotate_gatekeeper_key() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let carl = env.next_user();
+    let token_id = TokenId::from("123456");
+
+    token.grant_gatekeeper(owner, ali);
+    token.mint(ali, carl, Some(token_id.clone()), meta::verified_kyc());
+
+    token.rotate_gatekeeper_key(ali, bob);
+    assert!(token.is_gatekeeper(ali));
+    token.confirm_gatekeeper_key(bob, ali);
+    assert!(!token.is_gatekeeper(ali));
+    assert!(token.is_gatekeeper(bob));
+    assert_eq!(token.gatekeeper_key_identity(bob), Some(Key::Account(ali)));
+    assert_eq!(token.gatekeeper_key_identity(ali), Some(Key::Account(ali)));
+
+    assert_eq!(
+        token
+            .token_issuance(token_id.clone())
+            .map(|(issuer, _)| issuer),
+        Some(Key::Account(ali))
+    );
+    assert_eq!(token.gatekeeper_live_tokens(ali), 1);
+
+    token.burn(bob, carl, token_id);
+    assert_eq!(token.gatekeeper_live_tokens(ali), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_mint_from_rotated_key() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let carl = env.next_user();
+
+    token.grant_gatekeeper(owner, ali);
+    token.rotate_gatekeeper_key(ali, bob);
+    token.confirm_gatekeeper_key(bob, ali);
+    token.mint(ali, carl, None, meta::verified_kyc()); // panic here
+}
+
+#[test]
+#[should_panic]
+fn test_confirm_gatekeeper_key_from_other_key() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let carl = env.next_user();
+
+    token.grant_gatekeeper(owner, ali);
+    token.rotate_gatekeeper_key(ali, bob);
+    token.confirm_gatekeeper_key(carl, ali); // panic here
+}
+
+#[test]
+#[should_panic]
+fn test_confirm_gatekeeper_key_from_other_proposer() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let dan = env.next_user();
+
+    token.grant_gatekeeper(owner, ali);
+    token.grant_gatekeeper(owner, dan);
+    token.rotate_gatekeeper_key(ali, bob);
+    token.confirm_gatekeeper_key(bob, dan); // panic here
+}
+
+#[test]
+#[should_panic]
+fn test_rotate_onto_pending_key() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let dan = env.next_user();
+
+    token.grant_gatekeeper(owner, ali);
+    token.grant_gatekeeper(owner, dan);
+    token.rotate_gatekeeper_key(ali, bob);
+    token.rotate_gatekeeper_key(dan, bob); // panic here
+}
+
+#[test]
+#[should_panic]
+fn test_rotate_onto_gatekeeper_key() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let dan = env.next_user();
+
+    token.grant_gatekeeper(owner, ali);
+    token.grant_gatekeeper(owner, dan);
+    token.rotate_gatekeeper_key(ali, dan); // panic here
+}
+
+#[test]
+#[should_panic]
+fn test_rotate_onto_network_gatekeeper_key() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let dan = env.next_user();
+
+    token.register_network(owner, 1, network_info("partner", owner));
+    token.grant_gatekeeper(owner, ali);
+    token.grant_network_gatekeeper(owner, 1, dan);
+    token.rotate_gatekeeper_key(ali, dan); // panic here
+}
+
+#[test]
+#[should_panic]
+fn test_rotate_onto_retired_key() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let dan = env.next_user();
+
+    token.grant_gatekeeper(owner, ali);
+    token.grant_gatekeeper(owner, dan);
+    token.rotate_gatekeeper_key(ali, bob);
+    token.confirm_gatekeeper_key(bob, ali);
+    token.rotate_gatekeeper_key(dan, ali); // panic here
+}
+
+#[test]
+#[should_panic]
+fn test_mint_after_resignation() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.register_network(owner, 1, network_info("partner", owner));
+    token.grant_gatekeeper(owner, ali);
+    token.grant_network_gatekeeper(owner, 1, ali);
+    token.resign_gatekeeper(ali);
+
+    let options = MintOptions {
+        network: 1,
+        ..Default::default()
+    };
+    token.mint_with_options(ali, bob, None, meta::verified_kyc(), options); // panic here
+}
+
+#[test]
+fn test_mark_gatekeeper_compromised() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.grant_gatekeeper(owner, ali);
+    token.rotate_gatekeeper_key(ali, bob);
+    token.confirm_gatekeeper_key(bob, ali);
+
+    token.mark_gatekeeper_compromised(owner, bob, Some(0));
+    assert_eq!(token.gatekeeper_compromised_since(ali), Some(0));
+
+    token.mark_gatekeeper_compromised(owner, ali, None);
+    assert_eq!(token.gatekeeper_compromised_since(ali), None);
+}
+
+#[test]
+fn test_compromised_issuer_fails_verification() {
+    let (env, token, consumer, owner) = deploy_with_consumer();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.grant_gatekeeper(owner, ali);
+    token.mint(ali, bob, None, meta::active_kyc());
+    consumer.verify(owner, token.package_hash(), bob);
+    assert_eq!(consumer.last_verification(), (true, reason::VERIFIED));
+
+    token.mark_gatekeeper_compromised(owner, ali, Some(0));
+    consumer.verify(owner, token.package_hash(), bob);
+    assert_eq!(
+        consumer.last_verification(),
+        (false, reason::ISSUER_COMPROMISED)
+    );
+}
+
+#[test]
+fn test_list_compromised_tokens() {
+    let (env, token, consumer, owner) = deploy_with_consumer();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let carl = env.next_user();
+
+    token.grant_gatekeeper(owner, ali);
+    token.mint(ali, bob, Some(TokenId::from("1")), meta::active_kyc());
+    token.mint(owner, carl, Some(TokenId::from("2")), meta::active_kyc());
+    token.mint(ali, carl, Some(TokenId::from("3")), meta::active_kyc());
+    consumer.compromised_tokens(owner, token.package_hash(), ali, 0, 10);
+    assert_eq!(consumer.last_compromised_tokens(), Vec::<TokenId>::new());
+
+    token.mark_gatekeeper_compromised(owner, ali, Some(0));
+    consumer.compromised_tokens(owner, token.package_hash(), ali, 0, 10);
+    assert_eq!(
+        consumer.last_compromised_tokens(),
+        vec![TokenId::from("1"), TokenId::from("3")]
+    );
+    consumer.compromised_tokens(owner, token.package_hash(), ali, 1, 1);
+    assert_eq!(consumer.last_compromised_tokens(), Vec::<TokenId>::new());
+    consumer.compromised_tokens(owner, token.package_hash(), ali, 1, 2);
+    assert_eq!(consumer.last_compromised_tokens(), vec![TokenId::from("3")]);
+}
+
+#[test]
+#[should_panic]
+fn test_mark_gatekeeper_compromised_by_non_admin() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+
+    token.grant_gatekeeper(owner, ali);
+    token.mark_gatekeeper_compromised(ali, owner, Some(0)); // panic here
+}
+
+#[test]
+fn test_lift_gatekeeper_suspension() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.grant_gatekeeper(owner, ali);
+    token.suspend_gatekeeper(owner, ali, u64::MAX);
+    assert_eq!(token.gatekeeper_suspended_until(ali), Some(u64::MAX));
+    assert!(token.is_gatekeeper(ali));
+
+    token.lift_gatekeeper_suspension(owner, ali);
+    assert_eq!(token.gatekeeper_suspended_until(ali), None);
+
+    token.mint(ali, bob, None, meta::verified_kyc());
+    assert_eq!(token.balance_of(bob), U256::one());
+}
+
+#[test]
+#[should_panic]
+fn test_mint_while_suspended() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.grant_gatekeeper(owner, ali);
+    token.suspend_gatekeeper(owner, ali, u64::MAX);
+    token.mint(ali, bob, None, meta::verified_kyc()); // panic here
+}
+
+#[test]
+#[should_panic]
+fn test_set_token_meta_while_suspended() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    let token_id = TokenId::from("123456");
+
+    token.grant_gatekeeper(owner, ali);
+    token.mint(ali, bob, Some(token_id.clone()), meta::verified_kyc());
+    token.suspend_gatekeeper(owner, ali, u64::MAX);
+    token.update_token_meta(ali, token_id, "expiry".into(), "5555555".into()); // panic here
+}
+
+#[test]
+fn test_restricted_gatekeeper_permissions() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = TokenId::from("123456");
+
+    token.grant_gatekeeper(owner, ali);
+    token.mint(owner, bob, Some(token_id.clone()), meta::verified_kyc());
+    token.set_gatekeeper_permissions(owner, ali, permissions::REFRESH | permissions::FREEZE);
+    assert_eq!(
+        token.gatekeeper_permissions(ali),
+        Some(permissions::REFRESH | permissions::FREEZE)
+    );
+
+    token.update_token_meta(
+        ali,
+        token_id.clone(),
+        String::from("expiry"),
+        String::from("5555555"),
+    );
+    let mut expected_result = meta::verified_kyc();
+    expected_result.insert(String::from("expiry"), String::from("5555555"));
+    assert_eq!(token.token_meta(token_id).unwrap(), expected_result);
+}
+
+#[test]
+#[should_panic]
+fn test_update_status_from_admin() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = TokenId::from("123456");
+
+    token.mint(owner, ali, Some(token_id.clone()), meta::unverified_kyc());
+    token.update_token_meta(owner, token_id, "status".into(), "active".into()); // panic here
+}
+
+#[test]
+#[should_panic]
+fn test_mint_without_mint_permission() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.grant_gatekeeper(owner, ali);
+    token.set_gatekeeper_permissions(owner, ali, permissions::REFRESH);
+    token.mint(ali, bob, None, meta::verified_kyc()); // panic here
+}
+
+#[test]
+#[should_panic]
+fn test_edit_metadata_without_permission() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = TokenId::from("123456");
+
+    token.grant_gatekeeper(owner, ali);
+    token.set_gatekeeper_permissions(owner, ali, permissions::MINT | permissions::FREEZE);
+    token.mint(ali, bob, Some(token_id.clone()), meta::verified_kyc());
+    token.update_token_meta(
+        ali,
+        token_id,
+        namespaced_key(ali, "note"),
+        String::from("checked"),
+    ); // panic here
+}
+
+#[test]
+fn test_update_own_namespace() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = TokenId::from("123456");
+    let mut token_meta = meta::verified_kyc();
+    token_meta.insert(String::from("country"), String::from("DE"));
+
+    token.grant_gatekeeper(owner, ali);
+    token.mint(ali, bob, Some(token_id.clone()), token_meta.clone());
+    token.update_token_meta(
+        ali,
+        token_id.clone(),
+        namespaced_key(ali, "note"),
+        String::from("checked"),
+    );
+
+    token_meta.insert(namespaced_key(ali, "note"), String::from("checked"));
+    assert_eq!(token.token_meta(token_id).unwrap(), token_meta);
+}
+
+#[test]
+#[should_panic]
+fn test_update_immutable_meta_key() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = TokenId::from("123456");
+    let mut token_meta = meta::verified_kyc();
+    token_meta.insert(String::from("country"), String::from("DE"));
+
+    token.grant_gatekeeper(owner, ali);
+    token.mint(ali, bob, Some(token_id.clone()), token_meta);
+    token.update_token_meta(ali, token_id, "country".into(), "FR".into()); // panic here
+}
+
+#[test]
+#[should_panic]
+fn test_update_other_gatekeeper_namespace() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let carl = env.next_user();
+    let token_id = TokenId::from("123456");
+
+    token.grant_gatekeeper(owner, ali);
+    token.grant_gatekeeper(owner, bob);
+    token.mint(ali, carl, Some(token_id.clone()), meta::verified_kyc());
+    token.update_token_meta(
+        bob,
+        token_id,
+        namespaced_key(ali, "note"),
+        String::from("checked"),
+    ); // panic here
+}
+
+#[test]
+fn test_mint_with_typed_claims() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = TokenId::from("123456");
+
+    let mut typed_claims = BTreeMap::new();
+    typed_claims.insert(
+        String::from("birth_year"),
+        CLValue::from_t(1990u64).unwrap(),
+    );
+    typed_claims.insert(String::from("pep"), CLValue::from_t(false).unwrap());
+    let options = MintOptions {
+        typed_claims: typed_claims.clone(),
+        ..Default::default()
+    };
+    token.mint_with_options(
+        owner,
+        ali,
+        Some(token_id.clone()),
+        meta::verified_kyc(),
+        options,
+    );
+
+    assert_eq!(token.typed_claims(token_id.clone()), typed_claims);
+    let mut expected_meta = meta::verified_kyc();
+    expected_meta.insert(String::from("birth_year"), String::from("1990"));
+    expected_meta.insert(String::from("pep"), String::from("false"));
+    assert_eq!(token.token_meta(token_id.clone()).unwrap(), expected_meta);
+
+    token.burn(owner, ali, token_id.clone());
+    assert!(token.typed_claims(token_id).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_mint_with_reserved_typed_claim() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+
+    let mut typed_claims = BTreeMap::new();
+    typed_claims.insert(String::from("expiry"), CLValue::from_t(0u64).unwrap());
+    let options = MintOptions {
+        typed_claims,
+        ..Default::default()
+    };
+    token.mint_with_options(owner, ali, None, meta::verified_kyc(), options); // panic here
+}
+
+#[test]
+#[should_panic]
+fn test_set_reserved_typed_claim() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = TokenId::from("123456");
+
+    token.mint(owner, ali, Some(token_id.clone()), meta::verified_kyc());
+    token.set_typed_claim(
+        owner,
+        token_id,
+        String::from("status"),
+        Some(CLValue::from_t(String::from("ACTIVE")).unwrap()),
+    ); // panic here
+}
+
+#[test]
+fn test_edit_meta_with_typed_claims_after_refresh() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = TokenId::from("123456");
+
+    token.register_network(owner, 1, network_info("partner", owner));
+    token.set_network_token_policy(owner, 1, (1_000, true, false));
+    let mut typed_claims = BTreeMap::new();
+    typed_claims.insert(String::from("pep"), CLValue::from_t(false).unwrap());
+    let options = MintOptions {
+        network: 1,
+        typed_claims,
+        ..Default::default()
+    };
+    token.mint_with_options(
+        owner,
+        ali,
+        Some(token_id.clone()),
+        meta::verified_kyc(),
+        options,
+    );
+
+    token.refresh(owner, token_id.clone());
+    token.update_token_meta(
+        owner,
+        token_id.clone(),
+        namespaced_key(owner, "note"),
+        String::from("checked"),
+    );
+    let token_meta = token.token_meta(token_id).unwrap();
+    assert_eq!(token_meta.get("pep"), Some(&String::from("false")));
+    assert_eq!(
+        token_meta.get(&namespaced_key(owner, "note")),
+        Some(&String::from("checked"))
+    );
+}
+
+#[test]
+fn test_set_typed_claim_in_own_namespace() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = TokenId::from("123456");
+    let claim = namespaced_key(ali, "checked_at");
+
+    token.grant_gatekeeper(owner, ali);
+    token.mint(ali, bob, Some(token_id.clone()), meta::verified_kyc());
+    token.set_typed_claim(
+        ali,
+        token_id.clone(),
+        claim.clone(),
+        Some(CLValue::from_t(5555555u64).unwrap()),
+    );
+
+    let typed_claims = token.typed_claims(token_id.clone());
+    assert_eq!(
+        typed_claims
+            .get(&claim)
+            .cloned()
+            .map(|value| value.into_t::<u64>().unwrap()),
+        Some(5555555)
+    );
+    assert_eq!(
+        token.token_meta(token_id.clone()).unwrap().get(&claim),
+        Some(&String::from("5555555"))
+    );
+
+    token.set_typed_claim(ali, token_id.clone(), claim.clone(), None);
+    assert!(token.typed_claims(token_id.clone()).is_empty());
+    assert_eq!(token.token_meta(token_id).unwrap().get(&claim), None);
+}
+
+#[test]
+#[should_panic]
+fn test_update_typed_claim_as_string() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = TokenId::from("123456");
+    let claim = String::from("birth_year");
+
+    let mut typed_claims = BTreeMap::new();
+    typed_claims.insert(claim.clone(), CLValue::from_t(1990u64).unwrap());
+    let options = MintOptions {
+        typed_claims,
+        ..Default::default()
+    };
+    token.mint_with_options(
+        owner,
+        ali,
+        Some(token_id.clone()),
+        meta::verified_kyc(),
+        options,
+    );
+    token.update_token_meta(owner, token_id, claim, "1991".into()); // panic here
+}
+
+#[test]
+fn test_token_stamps() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = TokenId::from("123456");
+
+    token.grant_gatekeeper(owner, ali);
+    token.mint(ali, bob, Some(token_id.clone()), meta::verified_kyc());
+    assert_eq!(
+        token.token_stamps(token_id.clone()),
+        Some(((0, Key::Account(ali)), (0, Key::Account(ali))))
+    );
+
+    token.update_token_meta(owner, token_id.clone(), "expiry".into(), "5555555".into());
+    assert_eq!(
+        token.token_stamps(token_id.clone()),
+        Some(((0, Key::Account(ali)), (0, Key::Account(owner))))
+    );
+
+    token.burn(owner, bob, token_id.clone());
+    assert_eq!(token.token_stamps(token_id), None);
+}
+
+#[test]
+fn test_refresh_token() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = TokenId::from("123456");
+
+    token.register_network(owner, 1, network_info("partner", owner));
+    token.set_network_token_policy(owner, 1, (1_000, true, false));
+    token.set_network_refresh_interval(owner, 1, 500);
+    assert_eq!(token.network_refresh_interval(1), Some(500));
+
+    token.grant_gatekeeper(owner, ali);
+    let options = MintOptions {
+        network: 1,
+        ..Default::default()
+    };
+    token.mint_with_options(
+        ali,
+        bob,
+        Some(token_id.clone()),
+        meta::verified_kyc(),
+        options,
+    );
+    assert_eq!(token.last_refreshed(token_id.clone()), Some(0));
+
+    token.set_gatekeeper_permissions(owner, ali, permissions::REFRESH);
+    token.refresh(ali, token_id.clone());
+    assert_eq!(token.last_refreshed(token_id.clone()), Some(0));
+    assert_eq!(
+        token.token_stamps(token_id).map(|(_, updated)| updated),
+        Some((0, Key::Account(ali)))
+    );
+}
+
+#[test]
+fn test_refresh_restores_verification() {
+    let (env, token, consumer, owner) = deploy_with_consumer();
+    let ali = env.next_user();
+    let token_id = TokenId::from("123456");
+    let mut token_meta = meta::active_kyc();
+    token_meta.insert(String::from("expiry"), String::from("0"));
+
+    token.register_network(owner, 1, network_info("partner", owner));
+    token.set_network_token_policy(owner, 1, (1_000, true, false));
+    let options = MintOptions {
+        network: 1,
+        ..Default::default()
+    };
+    token.mint_with_options(owner, ali, Some(token_id.clone()), token_meta, options);
+    consumer.verify(owner, token.package_hash(), ali);
+    assert_eq!(consumer.last_verification(), (false, reason::EXPIRED));
+
+    token.refresh(owner, token_id);
+    consumer.verify(owner, token.package_hash(), ali);
+    assert_eq!(consumer.last_verification(), (true, reason::VERIFIED));
+}
+
+#[test]
+fn test_refresh_window_through_consumer() {
+    let (env, token, consumer, owner) = deploy_with_consumer();
+    let ali = env.next_user();
+    let token_id = TokenId::from("123456");
+
+    token.register_network(owner, 1, network_info("partner", owner));
+    token.set_network_token_policy(owner, 1, (0, true, false));
+    token.set_network_refresh_interval(owner, 1, 500);
+    let options = MintOptions {
+        network: 1,
+        ..Default::default()
+    };
+    token.mint_with_options(
+        owner,
+        ali,
+        Some(token_id.clone()),
+        meta::active_kyc(),
+        options,
+    );
+    consumer.verify_fresh(owner, token.package_hash(), ali);
+    assert_eq!(consumer.last_verification(), (true, reason::VERIFIED));
+
+    env.set_block_time(500);
+    consumer.verify_fresh(owner, token.package_hash(), ali);
+    assert_eq!(consumer.last_verification(), (false, reason::NEEDS_REFRESH));
+    consumer.verify(owner, token.package_hash(), ali);
+    assert_eq!(consumer.last_verification(), (true, reason::VERIFIED));
+
+    token.refresh(owner, token_id.clone());
+    assert_eq!(token.last_refreshed(token_id), Some(500));
+    consumer.verify_fresh(owner, token.package_hash(), ali);
+    assert_eq!(consumer.last_verification(), (true, reason::VERIFIED));
+}
+
+#[test]
+#[should_panic]
+fn test_refresh_in_non_refreshable_network() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = TokenId::from("123456");
+
+    token.mint(owner, ali, Some(token_id.clone()), meta::verified_kyc());
+    token.refresh(owner, token_id); // panic here
+}
+
+#[test]
+fn test_dispute_and_reinstate() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = TokenId::from("123456");
+
+    token.grant_gatekeeper(owner, ali);
+    token.mint(ali, bob, Some(token_id.clone()), meta::verified_kyc());
+    token.freeze(ali, token_id.clone(), 2);
+    assert_eq!(
+        token.token_meta(token_id.clone()).unwrap().get("status"),
+        Some(&String::from("frozen"))
+    );
+
+    token.dispute(bob, token_id.clone(), [7u8; 32]);
+    assert_eq!(
+        token.token_dispute(token_id.clone()),
+        Some(([7u8; 32], (0, Key::Account(bob))))
+    );
+
+    token.resolve_dispute(owner, token_id.clone(), true);
+    assert_eq!(token.token_dispute(token_id.clone()), None);
+    assert_eq!(
+        token.token_meta(token_id.clone()).unwrap(),
+        meta::verified_kyc()
+    );
+    assert_eq!(
+        token.token_actions(token_id),
+        vec![
+            (String::from("freeze"), 2, (0, Key::Account(ali))),
+            (String::from("dispute"), 0, (0, Key::Account(bob))),
+            (String::from("reinstate"), 0, (0, Key::Account(owner))),
+        ]
+    );
+}
+
+#[test]
+fn test_unfreeze_token() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = TokenId::from("123456");
+
+    token.grant_gatekeeper(owner, ali);
+    token.set_gatekeeper_permissions(owner, ali, permissions::FREEZE);
+    token.mint(owner, bob, Some(token_id.clone()), meta::active_kyc());
+    token.freeze(ali, token_id.clone(), 2);
+    token.unfreeze(ali, token_id.clone());
+
+    assert_eq!(
+        token.token_meta(token_id.clone()).unwrap(),
+        meta::active_kyc()
+    );
+    assert_eq!(
+        token.token_actions(token_id),
+        vec![
+            (String::from("freeze"), 2, (0, Key::Account(ali))),
+            (String::from("unfreeze"), 0, (0, Key::Account(ali))),
+        ]
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_unfreeze_revoked_token() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = TokenId::from("123456");
+
+    token.mint(owner, ali, Some(token_id.clone()), meta::active_kyc());
+    token.revoke(owner, token_id.clone(), 1);
+    token.unfreeze(owner, token_id); // panic here
+}
+
+#[test]
+fn test_freeze_cannot_undo_revocation() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = TokenId::from("123456");
+
+    token.grant_gatekeeper(owner, ali);
+    token.set_gatekeeper_permissions(owner, ali, permissions::FREEZE);
+    token.mint(owner, bob, Some(token_id.clone()), meta::active_kyc());
+    token.revoke(owner, token_id.clone(), 1);
+
+    token.call_expecting_failure(
+        ali,
+        "freeze",
+        runtime_args! { "token_id" => token_id.clone(), "reason" => 2u8 },
+    );
+    token.call_expecting_failure(
+        ali,
+        "unfreeze",
+        runtime_args! { "token_id" => token_id.clone() },
+    );
+    assert_eq!(
+        token.token_meta(token_id).unwrap().get("status"),
+        Some(&String::from("revoked"))
+    );
+}
+
+#[test]
+fn test_confirm_revocation() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = TokenId::from("123456");
+
+    token.mint(owner, ali, Some(token_id.clone()), meta::verified_kyc());
+    token.revoke(owner, token_id.clone(), 1);
+    token.dispute(ali, token_id.clone(), [7u8; 32]);
+    token.resolve_dispute(owner, token_id.clone(), false);
+
+    assert_eq!(
+        token.token_meta(token_id.clone()).unwrap().get("status"),
+        Some(&String::from("revoked"))
+    );
+    assert_eq!(
+        token
+            .token_actions(token_id)
+            .into_iter()
+            .map(|(action, reason, _)| (action, reason))
+            .collect::<Vec<_>>(),
+        vec![
+            (String::from("revoke"), 1),
+            (String::from("dispute"), 0),
+            (String::from("confirm"), 0),
+        ]
+    );
+}
+
+#[test]
+fn test_burn_keeps_token_actions() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = TokenId::from("123456");
+
+    token.mint(owner, ali, Some(token_id.clone()), meta::verified_kyc());
+    token.revoke(owner, token_id.clone(), 1);
+    token.burn(owner, ali, token_id.clone());
+
+    assert_eq!(
+        token.token_actions(token_id),
+        vec![
+            (String::from("revoke"), 1, (0, Key::Account(owner))),
+            (String::from("burn"), 0, (0, Key::Account(owner))),
+        ]
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_dispute_from_non_holder() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let token_id = TokenId::from("123456");
+
+    token.mint(owner, ali, Some(token_id.clone()), meta::verified_kyc());
+    token.freeze(owner, token_id.clone(), 2);
+    token.dispute(bob, token_id, [7u8; 32]); // panic here
+}
+
+#[test]
+#[should_panic]
+fn test_dispute_active_token() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = TokenId::from("123456");
+
+    token.mint(owner, ali, Some(token_id.clone()), meta::verified_kyc());
+    token.dispute(ali, token_id, [7u8; 32]); // panic here
+}
+
+#[test]
+fn test_link_key() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.mint(owner, ali, None, meta::active_kyc());
+    token.link_key(ali, bob);
+    assert_eq!(token.linked_holder(bob), None);
+
+    token.confirm_key_link(bob, ali);
+    assert_eq!(token.linked_holder(bob), Some(Key::Account(ali)));
+    assert_eq!(token.linked_keys(ali), vec![Key::Account(bob)]);
+
+    token.unlink_key(ali, bob);
+    assert_eq!(token.linked_holder(bob), None);
+    assert!(token.linked_keys(ali).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_link_key_without_credential() {
+    let (env, token, _) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.link_key(ali, bob); // panic here
+}
+
+#[test]
+#[should_panic]
+fn test_confirm_key_link_without_proposal() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.mint(owner, ali, None, meta::active_kyc());
+    token.link_key(ali, bob);
+    token.confirm_key_link(owner, ali); // panic here
+}
+
+#[test]
+#[should_panic]
+fn test_confirm_key_link_from_other_holder() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.mint(owner, ali, None, meta::active_kyc());
+    token.link_key(ali, bob);
+    token.confirm_key_link(bob, owner); // panic here
+}
+
+#[test]
+#[should_panic]
+fn test_link_key_pending_for_other_holder() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let bob = env.next_user();
+    let carl = env.next_user();
+
+    token.mint(owner, ali, None, meta::active_kyc());
+    token.mint(owner, carl, None, meta::active_kyc());
+    token.link_key(ali, bob);
+    token.link_key(carl, bob); // panic here
+}
+
+#[test]
+fn test_linked_key_passes_verification() {
+    let (env, token, consumer, owner) = deploy_with_consumer();
+    let ali = env.next_user();
+    let bob = env.next_user();
+
+    token.mint(owner, ali, None, meta::active_kyc());
+    consumer.verify(owner, token.package_hash(), bob);
+    assert_eq!(consumer.last_verification(), (false, reason::NO_TOKEN));
+
+    token.link_key(ali, bob);
+    token.confirm_key_link(bob, ali);
+    consumer.verify(owner, token.package_hash(), bob);
+    assert_eq!(consumer.last_verification(), (true, reason::VERIFIED));
+}
+
+#[test]
+fn test_set_gatekeeper_relayer() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let relayer = env.next_user();
+
+    token.grant_gatekeeper(owner, ali);
+    token.set_gatekeeper_relayer(ali, relayer, true);
+    assert!(token.is_gatekeeper_relayer(relayer, ali));
+    assert!(!token.is_gatekeeper_relayer(relayer, owner));
+
+    token.set_gatekeeper_relayer(ali, relayer, false);
+    assert!(!token.is_gatekeeper_relayer(relayer, ali));
+}
+
+#[test]
+#[should_panic]
+fn test_set_gatekeeper_relayer_after_resigning() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let relayer = env.next_user();
+
+    token.grant_gatekeeper(owner, ali);
+    token.resign_gatekeeper(ali);
+    token.set_gatekeeper_relayer(ali, relayer, true); // panic here
+}
+
+#[test]
+fn test_holds_and_burns_are_notified() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let token_id = TokenId::from("123456");
+
+    token.mint(owner, ali, Some(token_id.clone()), meta::active_kyc());
+    assert_eq!(token.notification_count(), 0);
+
+    token.revoke(owner, token_id.clone(), 3);
+    token.burn(owner, ali, token_id.clone());
+    assert_eq!(token.notification_count(), 2);
+    assert_eq!(
+        token.notification(0),
+        Some((Key::Account(ali), token_id.clone(), ("revoke".into(), 3)))
+    );
+    assert_eq!(
+        token.notification(1),
+        Some((Key::Account(ali), token_id, ("burn".into(), 0)))
+    );
+}
+
+#[test]
+fn test_deliver_notifications_to_consumer() {
+    let (env, token, consumer, owner) = deploy_with_consumer();
+    let ali = env.next_user();
+    let token_id = TokenId::from("123456");
+
+    consumer.subscribe(owner, token.package_hash());
+    assert_eq!(token.subscriber_cursor(consumer.package_hash()), Some(0));
+
+    token.mint(owner, ali, Some(token_id.clone()), meta::active_kyc());
+    token.revoke(owner, token_id.clone(), 3);
+    token.burn(owner, ali, token_id.clone());
+
+    token.deliver_notifications(owner, consumer.package_hash(), 1);
+    assert_eq!(
+        consumer.notifications(),
+        vec![(Key::Account(ali), token_id.clone(), ("revoke".into(), 3))]
+    );
+    assert_eq!(token.subscriber_cursor(consumer.package_hash()), Some(1));
+
+    token.deliver_notifications(owner, consumer.package_hash(), 10);
+    assert_eq!(
+        consumer.notifications(),
+        vec![
+            (Key::Account(ali), token_id.clone(), ("revoke".into(), 3)),
+            (Key::Account(ali), token_id, ("burn".into(), 0)),
+        ]
+    );
+    assert_eq!(token.subscriber_cursor(consumer.package_hash()), Some(2));
+}
+
+#[test]
+#[should_panic]
+fn test_subscribe_from_account() {
+    let (env, token, _) = deploy();
+    let ali = env.next_user();
+
+    token.subscribe(ali, "on_token_revoked"); // panic here
+}
+
+#[test]
+#[should_panic]
+fn test_deliver_notifications_to_unknown_subscriber() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+
+    assert_eq!(token.subscriber_cursor(ali), None);
+    token.deliver_notifications(owner, ali, 10); // panic here
+}
+
+#[test]
+fn test_trust_gateway() {
+    let (_, token, owner) = deploy();
+    let gateway = Key::Hash([7u8; 32]);
+    let mut networks = BTreeMap::new();
+    networks.insert(0u64, 2u64);
+
+    token.trust_gateway(owner, gateway, networks.clone());
+    assert_eq!(token.trusted_gateway_count(), 1);
+    assert_eq!(token.trusted_gateway_networks(gateway), Some(networks));
+
+    token.distrust_gateway(owner, gateway);
+    assert_eq!(token.trusted_gateway_count(), 0);
+    assert_eq!(token.trusted_gateway_networks(gateway), None);
+}
+
+#[test]
+#[should_panic]
+fn test_trust_gateway_from_non_admin() {
+    let (env, token, _) = deploy();
+    let ali = env.next_user();
+    let gateway = Key::Hash([7u8; 32]);
+
+    token.trust_gateway(ali, gateway, BTreeMap::new()); // panic here
+}
+
+#[test]
+fn test_trust_gateway_by_proposal() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+    let gateway = Key::Hash([7u8; 32]);
+
+    token.grant_admin(owner, ali);
+    token.set_multisig_config(owner, 2, 3_600_000);
+    token.propose(
+        owner,
+        "trust_gateway",
+        runtime_args! {
+            "gateway" => gateway,
+            "networks" => BTreeMap::<u64, u64>::new()
+        },
+    );
+    assert_eq!(token.trusted_gateway_count(), 0);
+
+    token.approve_proposal(ali, 0);
+    assert_eq!(token.trusted_gateway_count(), 1);
+}
+
+#[test]
+#[should_panic]
+fn test_trust_gateway_directly_above_threshold() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+
+    token.grant_admin(owner, ali);
+    token.set_multisig_config(owner, 2, 3_600_000);
+    token.trust_gateway(owner, Key::Hash([7u8; 32]), BTreeMap::new()); // panic here
+}
+
+#[test]
+#[should_panic]
+fn test_trust_too_many_gateways() {
+    let (_, token, owner) = deploy();
+
+    for byte in 0..5u8 {
+        token.trust_gateway(owner, Key::Hash([byte; 32]), BTreeMap::new());
+    }
+    token.trust_gateway(owner, Key::Hash([5u8; 32]), BTreeMap::new()); // panic here
+}
+
+#[test]
+fn test_verify_through_trusted_gateway() {
+    let (env, token, consumer, owner) = deploy_with_consumer();
+    let ali = env.next_user();
+    let partner = CIVICInstance::new(
+        &env,
+        "partner_token",
+        owner,
+        NAME,
+        SYMBOL,
+        meta::contract_meta(),
+        owner,
+    );
+
+    partner.mint(owner, ali, None, meta::active_kyc());
+    consumer.verify(owner, token.package_hash(), ali);
+    assert_eq!(consumer.last_verification(), (false, reason::NO_TOKEN));
+
+    token.trust_gateway(owner, partner.package_hash(), BTreeMap::new());
+    consumer.verify(owner, token.package_hash(), ali);
+    assert_eq!(consumer.last_verification(), (true, reason::VERIFIED));
+    consumer.is_kyc_proved(owner, token.package_hash(), ali);
+    assert!(consumer.last_kyc_proved());
+}
+
+#[test]
+#[should_panic]
+fn test_trust_account_as_gateway() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+
+    token.trust_gateway(owner, Key::Account(ali), BTreeMap::new()); // panic here
+}
+
+#[test]
+fn test_sunset() {
+    let (_, token, owner) = deploy();
+    let successor = Key::Hash([9u8; 32]);
+
+    token.sunset(owner, successor, true);
+    assert_eq!(token.successor(), successor);
+}
+
+#[test]
+#[should_panic]
+fn test_sunset_directly_above_threshold() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+
+    token.grant_admin(owner, ali);
+    token.set_multisig_config(owner, 2, 3_600_000);
+    token.sunset(owner, Key::Hash([9u8; 32]), true); // panic here
+}
+
+#[test]
+fn test_verify_forwarded_after_sunset() {
+    let (env, token, consumer, owner) = deploy_with_consumer();
+    let ali = env.next_user();
+    let successor = CIVICInstance::new(
+        &env,
+        "successor_token",
+        owner,
+        NAME,
+        SYMBOL,
+        meta::contract_meta(),
+        owner,
+    );
+
+    successor.mint(owner, ali, None, meta::active_kyc());
+    token.sunset(owner, successor.package_hash(), true);
+    consumer.verify(owner, token.package_hash(), ali);
+    assert_eq!(consumer.last_verification(), (true, reason::VERIFIED));
+    consumer.is_kyc_proved(owner, token.package_hash(), ali);
+    assert!(consumer.last_kyc_proved());
+}
+
+#[test]
+#[should_panic]
+fn test_mint_after_sunset() {
+    let (env, token, owner) = deploy();
+    let ali = env.next_user();
+
+    token.sunset(owner, Key::Hash([9u8; 32]), false);
+    token.mint(owner, ali, None, meta::active_kyc()); // panic here
+}
+
+#[test]
+#[should_panic]
+fn test_sunset_from_non_admin() {
+    let (env, token, _) = deploy();
+    let ali = env.next_user();
+
+    token.sunset(ali, Key::Hash([9u8; 32]), false); // panic here
+}
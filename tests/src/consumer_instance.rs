@@ -0,0 +1,212 @@
+use casper_types::{account::AccountHash, runtime_args, Key, RuntimeArgs, U256};
+use test_env::{TestContract, TestEnv};
+
+use crate::civic_instance::{Notification, TokenId};
+
+/// Token with its status, expiry and network, as listed by the KYC contract.
+pub type TokenSummary = (TokenId, (String, Option<u64>, u64));
+
+/// Contract calling the KYC contract the way a consumer would, storing what it
+/// gets back.
+pub struct ConsumerInstance(TestContract);
+
+impl ConsumerInstance {
+    pub fn new(env: &TestEnv, contract_name: &str, sender: AccountHash) -> ConsumerInstance {
+        ConsumerInstance(TestContract::new(
+            env,
+            "consumer-contract.wasm",
+            contract_name,
+            sender,
+            runtime_args! {},
+        ))
+    }
+
+    pub fn verify<T: Into<Key>>(&self, sender: AccountHash, gateway: Key, account: T) {
+        self.0.call_contract(
+            sender,
+            "verify",
+            runtime_args! {
+                "gateway" => gateway,
+                "account" => account.into(),
+                "index" => Option::<U256>::None
+            },
+        )
+    }
+
+    pub fn verify_fresh<T: Into<Key>>(&self, sender: AccountHash, gateway: Key, account: T) {
+        self.0.call_contract(
+            sender,
+            "verify_fresh",
+            runtime_args! {
+                "gateway" => gateway,
+                "account" => account.into(),
+                "index" => Option::<U256>::None
+            },
+        )
+    }
+
+    pub fn is_kyc_proved<T: Into<Key>>(&self, sender: AccountHash, gateway: Key, account: T) {
+        self.0.call_contract(
+            sender,
+            "is_kyc_proved",
+            runtime_args! {
+                "gateway" => gateway,
+                "account" => account.into(),
+                "index" => Option::<U256>::None
+            },
+        )
+    }
+
+    pub fn is_kyc_proved_at_level<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        gateway: Key,
+        account: T,
+        level: u8,
+    ) {
+        self.0.call_contract(
+            sender,
+            "is_kyc_proved_at_level",
+            runtime_args! {
+                "gateway" => gateway,
+                "account" => account.into(),
+                "level" => level
+            },
+        )
+    }
+
+    pub fn is_eligible<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        gateway: Key,
+        account: T,
+        policy_id: u64,
+    ) {
+        self.0.call_contract(
+            sender,
+            "is_eligible",
+            runtime_args! {
+                "gateway" => gateway,
+                "account" => account.into(),
+                "policy_id" => policy_id
+            },
+        )
+    }
+
+    pub fn verify_claim(
+        &self,
+        sender: AccountHash,
+        gateway: Key,
+        token_id: TokenId,
+        claim: &str,
+        value: &str,
+        salt: &str,
+    ) {
+        self.0.call_contract(
+            sender,
+            "verify_claim",
+            runtime_args! {
+                "gateway" => gateway,
+                "token_id" => token_id,
+                "claim" => claim.to_string(),
+                "value" => value.to_string(),
+                "salt" => salt.to_string()
+            },
+        )
+    }
+
+    pub fn tokens_of<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        gateway: Key,
+        owner: T,
+        offset: u64,
+        limit: u64,
+    ) {
+        self.0.call_contract(
+            sender,
+            "tokens_of",
+            runtime_args! {
+                "gateway" => gateway,
+                "owner" => owner.into(),
+                "offset" => offset,
+                "limit" => limit
+            },
+        )
+    }
+
+    pub fn tokens(&self, sender: AccountHash, gateway: Key, offset: u64, limit: u64) {
+        self.0.call_contract(
+            sender,
+            "tokens",
+            runtime_args! {
+                "gateway" => gateway,
+                "offset" => offset,
+                "limit" => limit
+            },
+        )
+    }
+
+    pub fn compromised_tokens<T: Into<Key>>(
+        &self,
+        sender: AccountHash,
+        gateway: Key,
+        gatekeeper: T,
+        offset: u64,
+        limit: u64,
+    ) {
+        self.0.call_contract(
+            sender,
+            "compromised_tokens",
+            runtime_args! {
+                "gateway" => gateway,
+                "gatekeeper" => gatekeeper.into(),
+                "offset" => offset,
+                "limit" => limit
+            },
+        )
+    }
+
+    pub fn subscribe(&self, sender: AccountHash, gateway: Key) {
+        self.0.call_contract(
+            sender,
+            "subscribe",
+            runtime_args! {
+                "gateway" => gateway
+            },
+        )
+    }
+
+    pub fn last_verification(&self) -> (bool, u8) {
+        self.0.query_named_key(String::from("last_verification"))
+    }
+
+    pub fn last_kyc_proved(&self) -> bool {
+        self.0.query_named_key(String::from("last_kyc_proved"))
+    }
+
+    pub fn last_eligibility(&self) -> (bool, u8) {
+        self.0.query_named_key(String::from("last_eligibility"))
+    }
+
+    pub fn last_claim_verified(&self) -> bool {
+        self.0.query_named_key(String::from("last_claim_verified"))
+    }
+
+    pub fn last_tokens(&self) -> Vec<TokenSummary> {
+        self.0.query_named_key(String::from("last_tokens"))
+    }
+
+    pub fn last_compromised_tokens(&self) -> Vec<TokenId> {
+        self.0
+            .query_named_key(String::from("last_compromised_tokens"))
+    }
+
+    pub fn notifications(&self) -> Vec<Notification> {
+        self.0.query_named_key(String::from("notifications"))
+    }
+
+    pub fn package_hash(&self) -> Key {
+        Key::Hash(self.0.package_hash())
+    }
+}
@@ -3,3 +3,6 @@ pub mod civic_tests;
 
 #[cfg(test)]
 pub mod civic_instance;
+
+#[cfg(test)]
+pub mod consumer_instance;
@@ -0,0 +1,44 @@
+//! Salted claim commitments shared by the gateway token contract and off-chain tooling.
+//!
+//! A commitment is the blake2b-256 hash of the claim name, the claim value and a salt,
+//! each serialized as a length-prefixed string the same way `casper_types` serializes
+//! `String`. The contract hashes the same preimage with `runtime::blake2b`.
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+pub type ClaimCommitment = [u8; 32];
+
+pub fn claim_preimage(claim: &str, value: &str, salt: &str) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(12 + claim.len() + value.len() + salt.len());
+    append_field(&mut bytes, claim);
+    append_field(&mut bytes, value);
+    append_field(&mut bytes, salt);
+    bytes
+}
+
+#[cfg(feature = "hashing")]
+pub fn commit_claim(claim: &str, value: &str, salt: &str) -> ClaimCommitment {
+    use blake2::{
+        digest::{Update, VariableOutput},
+        VarBlake2b,
+    };
+
+    let mut hasher = VarBlake2b::new(32).unwrap();
+    hasher.update(claim_preimage(claim, value, salt));
+    let mut ret = [0u8; 32];
+    hasher.finalize_variable(|hash| ret.clone_from_slice(hash));
+    ret
+}
+
+#[cfg(feature = "hashing")]
+pub fn verify_claim(commitment: &ClaimCommitment, claim: &str, value: &str, salt: &str) -> bool {
+    commit_claim(claim, value, salt) == *commitment
+}
+
+fn append_field(bytes: &mut Vec<u8>, field: &str) {
+    bytes.extend_from_slice(&(field.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(field.as_bytes());
+}
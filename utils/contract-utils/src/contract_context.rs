@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 use casper_contract::unwrap_or_revert::UnwrapOrRevert;
 use casper_types::{system::CallStackElement, Key};
 
@@ -12,6 +14,21 @@ pub trait ContractContext<Storage: ContractStorage> {
         element_to_key(caller.unwrap_or_revert())
     }
 
+    /// Account that signed the deploy, however many contracts it went through.
+    fn get_origin_caller(&self) -> Key {
+        let call_stack = self.storage().call_stack();
+        element_to_key(call_stack.first().unwrap_or_revert())
+    }
+
+    /// Keys of the whole call stack, from the origin caller to this contract.
+    fn call_chain(&self) -> Vec<Key> {
+        self.storage()
+            .call_stack()
+            .iter()
+            .map(element_to_key)
+            .collect()
+    }
+
     fn self_addr(&mut self) -> Key {
         let call_stack = self.storage().call_stack();
         element_to_key(call_stack.last().unwrap_or_revert())
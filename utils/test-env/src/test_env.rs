@@ -20,16 +20,42 @@ impl TestEnv {
     }
 
     pub fn run(&self, sender: AccountHash, session_code: DeploySource, session_args: RuntimeArgs) {
+        let mut state = self.state.lock().unwrap();
+        let block_time = state.block_time;
         deploy(
-            &mut self.state.lock().unwrap().builder,
+            &mut state.builder,
             &sender,
             &session_code,
             session_args,
             true,
-            None,
+            block_time,
         )
     }
 
+    /// Runs a deploy that has to revert, leaving the state as it was.
+    pub fn run_expecting_failure(
+        &self,
+        sender: AccountHash,
+        session_code: DeploySource,
+        session_args: RuntimeArgs,
+    ) {
+        let mut state = self.state.lock().unwrap();
+        let block_time = state.block_time;
+        deploy(
+            &mut state.builder,
+            &sender,
+            &session_code,
+            session_args,
+            false,
+            block_time,
+        )
+    }
+
+    /// Sets the block time, in milliseconds, of every following deploy.
+    pub fn set_block_time(&self, block_time: u64) {
+        self.state.lock().unwrap().block_time = Some(block_time);
+    }
+
     pub fn next_user(&self) -> AccountHash {
         self.state.lock().unwrap().next_user()
     }
@@ -67,6 +93,7 @@ impl Default for TestEnv {
 struct TestEnvState {
     builder: InMemoryWasmTestBuilder,
     accounts: Vec<AccountHash>,
+    block_time: Option<u64>,
 }
 
 impl TestEnvState {
@@ -85,7 +112,11 @@ impl TestEnvState {
                 .commit();
         }
 
-        TestEnvState { builder, accounts }
+        TestEnvState {
+            builder,
+            accounts,
+            block_time: None,
+        }
     }
 
     pub fn _new_with_users(user_secrets: &[[u8; 32]]) -> TestEnvState {
@@ -104,7 +135,11 @@ impl TestEnvState {
                 .commit();
         }
 
-        TestEnvState { builder, accounts }
+        TestEnvState {
+            builder,
+            accounts,
+            block_time: None,
+        }
     }
 
     pub fn next_user(&mut self) -> AccountHash {
@@ -123,7 +158,7 @@ impl TestEnvState {
             &session_code,
             session_args,
             true,
-            None,
+            self.block_time,
         )
     }
 